@@ -0,0 +1,156 @@
+use proc_macro2::{Ident, TokenStream};
+use quote::quote;
+use syn::spanned::Spanned;
+use syn::{parse2, Data, DeriveInput, Error, Fields, Result, Type};
+
+use crate::impl_command::{check_arguments, reserve_segments, Argument, Constraint};
+use crate::paths;
+
+pub fn impl_arguments(input: TokenStream) -> Result<TokenStream> {
+    let input = parse2::<DeriveInput>(input)?;
+    let arguments = parse_fields(&input)?;
+
+    arguments_impl(&input.ident, &arguments)
+}
+
+pub fn impl_from_segments(input: TokenStream) -> Result<TokenStream> {
+    let input = parse2::<DeriveInput>(input)?;
+    let arguments = parse_fields(&input)?;
+
+    Ok(from_segments_impl(&input.ident, &arguments))
+}
+
+/// Returns the fields of the set of arguments, in order.
+fn parse_fields(input: &DeriveInput) -> Result<Vec<Argument>> {
+    if !input.generics.params.is_empty() {
+        return Err(Error::new(
+            input.generics.span(),
+            "a set of arguments cannot have generic parameters",
+        ));
+    }
+
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => &fields.named,
+            fields => {
+                return Err(Error::new(fields.span(), "a set of arguments must have named fields"))
+            },
+        },
+        _ => return Err(Error::new(input.ident.span(), "a set of arguments must be a struct")),
+    };
+
+    let mut arguments = Vec::with_capacity(fields.len());
+
+    for (index, field) in fields.iter().enumerate() {
+        // Attributes of other macros, such as documentation, are ignored.
+        let attrs = field
+            .attrs
+            .iter()
+            .filter(|a| {
+                ["rest", "arguments", "default", "parse_with", "count"]
+                    .iter()
+                    .chain(Constraint::names())
+                    .any(|n| a.path.is_ident(n))
+            })
+            .cloned()
+            .collect::<Vec<_>>();
+
+        if let Some(attr) = attrs.iter().find(|a| a.path.is_ident("arguments")) {
+            return Err(Error::new(
+                attr.span(),
+                "a set of arguments cannot contain another set of arguments",
+            ));
+        }
+
+        let name = field.ident.clone().unwrap();
+        let ty = Box::new(field.ty.clone());
+
+        arguments.push(Argument::from_parts(name, ty, &attrs, index)?);
+    }
+
+    check_arguments(&arguments)?;
+    reserve_segments(&mut arguments);
+
+    Ok(arguments)
+}
+
+fn arguments_impl(name: &Ident, arguments: &[Argument]) -> Result<TokenStream> {
+    let data: Type = parse2(quote!(__D))?;
+    let error: Type = parse2(quote!(__E))?;
+
+    let arguments_trait = paths::arguments_trait(&data, &error);
+    let argument_trait = paths::argument_trait(&data, &error);
+    let context_type = paths::context_type(&data, &error);
+    let message_type = paths::message_type();
+    let asegsty = paths::argument_segments_type();
+    let argument_error = paths::argument_error_type();
+    let boxed_error = paths::boxed_error_type();
+
+    let inner_tys = inner_types(arguments);
+    let names = arguments.iter().map(|arg| &arg.name);
+    let parsing = arguments.iter().map(|arg| {
+        let parameter = arg.parameter(&argument_trait);
+        let errors = quote!(.map_err(|e| e.boxed().with_parameter(#parameter)));
+
+        arg.parsing(Some((&quote!(ctx), &quote!(msg))), &quote!((*segments)), &errors)
+    });
+
+    Ok(quote! {
+        impl<#data, #error> #arguments_trait for #name
+        where
+            #data: Send + Sync,
+            #error: Send + Sync,
+            #(#inner_tys: #argument_trait,
+            <#inner_tys as #argument_trait>::Error: std::error::Error + Send + Sync + 'static,)*
+        {
+            fn parse_arguments<'fut>(
+                ctx: &'fut #context_type,
+                msg: &'fut #message_type,
+                segments: &'fut mut #asegsty<'_>,
+            ) -> std::pin::Pin<Box<
+                dyn std::future::Future<Output = Result<Self, #argument_error<#boxed_error>>>
+                    + Send
+                    + 'fut,
+            >> {
+                Box::pin(async move {
+                    #(#parsing)*
+
+                    Ok(Self { #(#names),* })
+                })
+            }
+        }
+    })
+}
+
+fn from_segments_impl(name: &Ident, arguments: &[Argument]) -> TokenStream {
+    let from_segments = paths::from_segments_trait();
+    let asegsty = paths::argument_segments_type();
+    let argument_error = paths::argument_error_type();
+    let boxed_error = paths::boxed_error_type();
+
+    let names = arguments.iter().map(|arg| &arg.name);
+    let parsing = arguments.iter().map(|arg| {
+        let parameter = arg.parameter(&from_segments);
+        let errors = quote!(.map_err(|e| e.boxed().with_parameter(#parameter)));
+
+        arg.parsing(None, &quote!((*segments)), &errors)
+    });
+
+    quote! {
+        impl #from_segments for #name {
+            type Error = #argument_error<#boxed_error>;
+
+            fn from_segments(segments: &mut #asegsty<'_>) -> Result<Self, Self::Error> {
+                #(#parsing)*
+
+                Ok(Self { #(#names),* })
+            }
+        }
+    }
+}
+
+/// Returns the types of the arguments parsed through their implementations of
+/// the parsing traits, which are bound by the implementations of the set.
+fn inner_types(arguments: &[Argument]) -> Vec<&Type> {
+    arguments.iter().filter(|arg| arg.parser.is_none()).map(|arg| &arg.inner_ty).collect()
+}
@@ -0,0 +1,124 @@
+use std::collections::HashSet;
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::spanned::Spanned;
+use syn::{parse2, Data, DeriveInput, Error, Fields, Lit, Meta, NestedMeta, Result, Variant};
+
+use crate::paths;
+
+pub fn impl_choice(input: TokenStream) -> Result<TokenStream> {
+    let input = parse2::<DeriveInput>(input)?;
+
+    if !input.generics.params.is_empty() {
+        return Err(Error::new(input.generics.span(), "a choice cannot have generic parameters"));
+    }
+
+    let variants = match &input.data {
+        Data::Enum(data) => &data.variants,
+        _ => return Err(Error::new(input.ident.span(), "a choice must be an enum")),
+    };
+
+    if variants.is_empty() {
+        return Err(Error::new(input.ident.span(), "a choice must have at least one variant"));
+    }
+
+    let choices = variants.iter().map(ChoiceVariant::new).collect::<Result<Vec<_>>>()?;
+
+    // Names are compared case-insensitively, so names differing only by case
+    // would make every choice after the first unreachable.
+    let mut seen = HashSet::new();
+
+    for choice in &choices {
+        for name in std::iter::once(&choice.name).chain(&choice.aliases) {
+            if !seen.insert(name.to_ascii_lowercase()) {
+                return Err(Error::new(
+                    choice.variant.ident.span(),
+                    format!("the name `{}` is given more than once", name),
+                ));
+            }
+        }
+    }
+
+    let name = &input.ident;
+    let choice_trait = paths::choice_trait();
+
+    let variant_names = choices.iter().map(|c| &c.variant.ident).collect::<Vec<_>>();
+    let names = choices.iter().map(|c| &c.name).collect::<Vec<_>>();
+    let matches = choices.iter().map(|c| {
+        let names = std::iter::once(&c.name).chain(&c.aliases);
+        quote!(#(choice.eq_ignore_ascii_case(#names))||*)
+    });
+
+    Ok(quote! {
+        impl #choice_trait for #name {
+            const CHOICES: &'static [&'static str] = &[#(#names),*];
+
+            fn from_name(choice: &str) -> Option<Self> {
+                #(if #matches {
+                    return Some(Self::#variant_names);
+                })*
+
+                None
+            }
+
+            fn name(&self) -> &'static str {
+                match self {
+                    #(Self::#variant_names => #names,)*
+                }
+            }
+        }
+    })
+}
+
+/// A variant of a choice, along with its names.
+struct ChoiceVariant<'a> {
+    variant: &'a Variant,
+    name: String,
+    aliases: Vec<String>,
+}
+
+impl<'a> ChoiceVariant<'a> {
+    fn new(variant: &'a Variant) -> Result<Self> {
+        if !matches!(variant.fields, Fields::Unit) {
+            return Err(Error::new(variant.fields.span(), "a choice cannot have fields"));
+        }
+
+        let mut name = variant.ident.to_string().to_lowercase();
+        let mut aliases = Vec::new();
+
+        for attr in variant.attrs.iter().filter(|a| a.path.is_ident("choice")) {
+            let list = match attr.parse_meta()? {
+                Meta::List(list) => list,
+                meta => {
+                    return Err(Error::new(
+                        meta.span(),
+                        "expected a list of names, such as `#[choice(alias = \"...\")]`",
+                    ))
+                },
+            };
+
+            for nested in list.nested {
+                let (key, value) = match &nested {
+                    NestedMeta::Meta(Meta::NameValue(nv)) => match &nv.lit {
+                        Lit::Str(value) => (nv.path.get_ident(), value.value()),
+                        lit => return Err(Error::new(lit.span(), "expected a string")),
+                    },
+                    _ => return Err(Error::new(nested.span(), "expected `name` or `alias`")),
+                };
+
+                match key.map(ToString::to_string).as_deref() {
+                    Some("name") => name = value,
+                    Some("alias") => aliases.push(value),
+                    _ => return Err(Error::new(nested.span(), "expected `name` or `alias`")),
+                }
+            }
+        }
+
+        Ok(Self {
+            variant,
+            name,
+            aliases,
+        })
+    }
+}
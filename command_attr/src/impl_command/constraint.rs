@@ -0,0 +1,103 @@
+use proc_macro2::TokenStream;
+use quote::{quote, ToTokens};
+use syn::{Attribute, Error, Expr, ExprRange, LitStr, RangeLimits, Result};
+
+use crate::paths;
+
+/// A constraint on the value of a parameter, given by an attribute.
+pub(crate) enum Constraint {
+    /// `#[range(1..=100)]`
+    Range(ExprRange),
+    /// `#[max_length(200)]`
+    MaxLength(Expr),
+    /// `#[regex("...")]`
+    Regex(LitStr),
+}
+
+impl Constraint {
+    /// Returns the names of the attributes of constraints.
+    pub(crate) fn names() -> &'static [&'static str] {
+        &["range", "max_length", "regex"]
+    }
+
+    /// Parses a constraint, if the attribute is one.
+    pub(crate) fn parse(attr: &Attribute) -> Result<Option<Self>> {
+        let constraint = if attr.path.is_ident("range") {
+            Constraint::Range(attr.parse_args()?)
+        } else if attr.path.is_ident("max_length") {
+            Constraint::MaxLength(attr.parse_args()?)
+        } else if attr.path.is_ident("regex") {
+            let regex = attr.parse_args::<LitStr>()?;
+
+            // Invalid patterns are rejected here rather than when first matched.
+            if let Err(err) = regex::Regex::new(&regex.value()) {
+                return Err(Error::new(regex.span(), format!("invalid regex: {}", err)));
+            }
+
+            Constraint::Regex(regex)
+        } else {
+            return Ok(None);
+        };
+
+        Ok(Some(constraint))
+    }
+
+    /// Returns the expression describing the constraint as metadata.
+    ///
+    /// The bounds of ranges are described as written, as they may be of any type.
+    pub(crate) fn metadata(&self) -> TokenStream {
+        let constraint = paths::constraint_type();
+
+        match self {
+            Constraint::Range(range) => {
+                let bound = |expr: Option<&Expr>, inclusive: bool| match expr.map(bound_text) {
+                    Some(text) if inclusive => quote!(std::ops::Bound::Included(#text)),
+                    Some(text) => quote!(std::ops::Bound::Excluded(#text)),
+                    None => quote!(std::ops::Bound::Unbounded),
+                };
+
+                let start = bound(range.from.as_deref(), true);
+                let end =
+                    bound(range.to.as_deref(), matches!(range.limits, RangeLimits::Closed(_)));
+
+                quote!(#constraint::Range { start: #start, end: #end })
+            },
+            Constraint::MaxLength(len) => quote!(#constraint::MaxLength(#len)),
+            Constraint::Regex(regex) => quote!(#constraint::Regex(#regex)),
+        }
+    }
+
+    /// Returns the expression checking whether a reference to a value satisfies
+    /// the constraint.
+    pub(crate) fn condition(&self, value: &TokenStream) -> TokenStream {
+        match self {
+            Constraint::Range(range) => quote!((#range).contains(#value)),
+            Constraint::MaxLength(len) => {
+                quote!(std::convert::AsRef::<str>::as_ref(#value).chars().count() <= #len)
+            },
+            Constraint::Regex(regex) => {
+                let regex_type = paths::regex_type();
+
+                // The pattern was validated when parsing the attribute.
+                quote! {{
+                    static __REGEX: std::sync::OnceLock<#regex_type> = std::sync::OnceLock::new();
+
+                    __REGEX
+                        .get_or_init(|| #regex_type::new(#regex).unwrap())
+                        .is_match(std::convert::AsRef::<str>::as_ref(#value))
+                }}
+            },
+        }
+    }
+}
+
+/// Returns the bound of a range as written in the source, without the spacing
+/// of the token stream.
+fn bound_text(expr: &Expr) -> String {
+    let text = expr.to_token_stream().to_string().replace(" :: ", "::");
+
+    match text.strip_prefix("- ") {
+        Some(number) => format!("-{}", number),
+        None => text,
+    }
+}
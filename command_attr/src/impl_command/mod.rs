@@ -1,13 +1,17 @@
 use proc_macro2::{Ident, TokenStream};
 use quote::{format_ident, quote, ToTokens};
+use syn::parse::{Parse, ParseStream};
 use syn::spanned::Spanned;
-use syn::{parse2, Attribute, Error, FnArg, ItemFn, Path, Result, Type};
+use syn::{parse2, Attribute, Error, Expr, FnArg, ItemFn, Lit, LitChar, Meta, NestedMeta, Pat};
+use syn::{Path, Result, Token, Type};
 
 use crate::paths;
 use crate::utils::{self, AttributeArgs};
 
+mod constraint;
 mod options;
 
+pub(crate) use constraint::Constraint;
 use options::Options;
 
 pub fn impl_command(attr: TokenStream, input: TokenStream) -> Result<TokenStream> {
@@ -22,9 +26,9 @@ pub fn impl_command(attr: TokenStream, input: TokenStream) -> Result<TokenStream
     let (ctx_name, data, error) = utils::parse_generics(&fun.sig)?;
     let options = Options::parse(&mut fun.attrs)?;
 
-    parse_arguments(ctx_name, &mut fun, &options)?;
+    let parameters = parse_arguments(ctx_name, &data, &error, &mut fun, &options)?;
 
-    let builder_fn = builder_fn(&data, &error, &mut fun, names, &options);
+    let builder_fn = builder_fn(&data, &error, &mut fun, names, &options, &parameters);
 
     let hook_macro = paths::hook_macro();
 
@@ -45,6 +49,7 @@ fn builder_fn(
     function: &mut ItemFn,
     mut names: Vec<String>,
     options: &Options,
+    parameters: &[TokenStream],
 ) -> TokenStream {
     let name = names.remove(0);
     let aliases = names;
@@ -68,100 +73,289 @@ fn builder_fn(
             #command_builder::new(#name)
                 #(.name(#aliases))*
                 .function(#function_name)
+                #(.parameter(#parameters))*
                 #options
                 .build()
         }
     }
 }
 
-fn parse_arguments(ctx_name: Ident, function: &mut ItemFn, options: &Options) -> Result<()> {
+/// Replaces the parameters of the command function by parsing of arguments.
+///
+/// Returns the descriptions of the parameters, in order.
+fn parse_arguments(
+    ctx_name: Ident,
+    data: &Type,
+    error: &Type,
+    function: &mut ItemFn,
+    options: &Options,
+) -> Result<Vec<TokenStream>> {
+    let mut inputs = Vec::new();
+
+    while function.sig.inputs.len() > 2 {
+        inputs.push(function.sig.inputs.pop().unwrap().into_value());
+    }
+
+    inputs.reverse();
+
+    let mut names = Vec::new();
     let mut arguments = Vec::new();
+    let mut named = Vec::new();
+    let mut implicit = Vec::new();
+    let mut parameters = Vec::new();
 
-    let mut len = function.sig.inputs.len();
-    while len > 2 {
-        let argument = function.sig.inputs.pop().unwrap().into_value();
+    let argument_trait = paths::argument_trait(data, error);
 
-        arguments.push(Argument::new(argument)?);
+    for argument in inputs {
+        match NamedArgument::new(&argument)? {
+            Some(arg) => {
+                names.push(arg.name.clone());
+                parameters.push(arg.parameter(&argument_trait));
+                named.push(arg);
+            },
+            None => match ImplicitArgument::new(&argument)? {
+                // Implicit arguments are not given by the user, and are thus not
+                // described as parameters.
+                Some(arg) => {
+                    names.push(arg.name.clone());
+                    implicit.push(arg);
+                },
+                None => {
+                    // Only positional arguments are counted, as the others may
+                    // be placed anywhere.
+                    let arg = Argument::new(argument, arguments.len())?;
+                    names.push(arg.name.clone());
+                    parameters.push(arg.parameter(&argument_trait));
+                    arguments.push(arg);
+                },
+            },
+        }
+    }
 
-        len -= 1;
+    check_arguments(&arguments)?;
+    reserve_segments(&mut arguments);
+
+    // Commands without parameters are only checked for extraneous arguments if
+    // strict mode is enabled for them, as the check requires their error type to
+    // be convertible from argument errors.
+    if names.is_empty() && options.strict_arguments != Some(true) {
+        return Ok(parameters);
     }
 
-    if !arguments.is_empty() {
-        arguments.reverse();
+    let msg_name = message_name(function)?;
+
+    let delimiter = options.delimiter.as_ref().map_or(" ", String::as_str);
+    let asegsty = paths::argument_segments_type();
+
+    let stmts = &function.block.stmts;
 
-        check_arguments(&arguments)?;
+    let implicit_names = implicit.iter().map(|arg| &arg.name);
+    let implicit_tys = implicit.iter().map(|arg| &arg.ty);
+    let implicit_values = implicit.iter().map(|arg| arg.value(&ctx_name, &msg_name));
 
-        let delimiter = options.delimiter.as_ref().map_or(" ", String::as_str);
-        let asegsty = paths::argument_segments_type();
+    let mut source = quote!(&#ctx_name.args);
+    let mut named_parsing = TokenStream::new();
 
-        let b = &function.block;
+    // Spans of positional arguments are relative to the arguments left by
+    // named arguments, not to `Context::args`.
+    let mut map_span = TokenStream::new();
 
-        let argument_names = arguments.iter().map(|arg| &arg.name).collect::<Vec<_>>();
-        let argument_tys = arguments.iter().map(|arg| &arg.ty).collect::<Vec<_>>();
-        let argument_kinds = arguments.iter().map(|arg| &arg.kind).collect::<Vec<_>>();
+    if !named.is_empty() {
+        let named_ty = paths::named_arguments_type();
 
-        function.block = parse2(quote! {{
-            let (#(#argument_names),*) = {
-                // Place the segments into its scope to allow mutation of `Context::args`
-                // afterwards, as `ArgumentSegments` holds a reference to the source string.
-                let mut __args = #asegsty::new(&#ctx_name.args, #delimiter);
+        let named_names = named.iter().map(|arg| &arg.name);
+        let named_tys = named.iter().map(|arg| &arg.ty);
+        let named_values = named.iter().map(|arg| {
+            let value = arg.value(&ctx_name, &msg_name);
 
-                #(let #argument_names: #argument_tys = #argument_kinds(&mut __args)?;)*
+            match arg.kind {
+                NamedType::Flag => value,
+                _ => {
+                    let parameter = arg.parameter(&argument_trait);
+                    quote!(#value.map_err(|e| e.with_parameter(#parameter))?)
+                },
+            }
+        });
+
+        named_parsing = quote! {
+            let mut __named = #named_ty::new(&#ctx_name.args, #delimiter, &__quotes);
 
-                (#(#argument_names),*)
+            #(let #named_names: #named_tys = #named_values;)*
+
+            let __positional = __named.positional();
+        };
+
+        source = quote!(&__positional);
+        map_span = quote!(.map_span(|i| __named.source_position(i)));
+    }
+
+    let mut positional_parsing = quote! {
+        let __args = #asegsty::with_quotes(#source, #delimiter, &__quotes);
+    };
+
+    if !arguments.is_empty() {
+        let context = (&quote!(&#ctx_name), &quote!(#msg_name));
+        let argument_parsing = arguments.iter().map(|arg| {
+            // Sets of arguments describe their own fields as the parameter.
+            let parameter = match arg.kind {
+                ArgumentType::Arguments => TokenStream::new(),
+                _ => {
+                    let parameter = arg.parameter(&argument_trait);
+                    quote!(.with_parameter(#parameter))
+                },
+            };
+
+            let errors = if parameter.is_empty() && map_span.is_empty() {
+                TokenStream::new()
+            } else {
+                quote!(.map_err(|e| e #parameter #map_span))
             };
 
-            #b
-        }})?;
+            arg.parsing(Some(context), &quote!(__args), &errors)
+        });
+
+        positional_parsing = quote! {
+            let mut __args = #asegsty::with_quotes(#source, #delimiter, &__quotes);
+
+            #(#argument_parsing)*
+        };
+    }
+
+    let check_extraneous = paths::check_extraneous_func();
+    let extraneous_errors =
+        if map_span.is_empty() { TokenStream::new() } else { quote!(.map_err(|e| e #map_span)) };
+    let check = quote!(#check_extraneous(&__args) #extraneous_errors?;);
+
+    // Strict mode is left to the configuration, unless the command decides it.
+    let (configuration, extraneous_check) = match options.strict_arguments {
+        None => (
+            quote! {
+                let (__quotes, __strict) = {
+                    let __conf = #ctx_name.conf.lock().await;
+                    let __strict = __conf
+                        .commands
+                        .get(#ctx_name.command_id)
+                        .and_then(|c| c.strict_arguments)
+                        .unwrap_or(__conf.strict_arguments);
+
+                    (__conf.quotes.clone(), __strict)
+                };
+            },
+            quote!(if __strict { #check }),
+        ),
+        Some(strict) => (
+            quote!(let __quotes = #ctx_name.conf.lock().await.quotes.clone();),
+            if strict { check } else { TokenStream::new() },
+        ),
+    };
+
+    // Commands without parameters only check for extraneous arguments.
+    let (bindings, values) = if names.is_empty() {
+        (TokenStream::new(), TokenStream::new())
+    } else {
+        (quote!(let (#(#names),*) =), quote!((#(#names),*)))
+    };
+
+    // The statements of the command are placed after the parsing, rather than
+    // nested in a block, to avoid warnings about unnecessary braces.
+    function.block = parse2(quote! {{
+        #bindings {
+            // Place the segments into its scope to allow mutation of `Context::args`
+            // afterwards, as `ArgumentSegments` holds a reference to the source string.
+            #configuration
+
+            #(let #implicit_names: #implicit_tys = #implicit_values;)*
+
+            #named_parsing
+            #positional_parsing
+
+            #extraneous_check
+
+            #values
+        };
+
+        #(#stmts)*
+    }})?;
+
+    Ok(parameters)
+}
+
+/// Returns the expression constructing the description of a positional parameter,
+/// used in errors.
+pub(crate) fn parameter(name: &Ident, ty: &Type, index: usize) -> TokenStream {
+    let parameter = paths::parameter_type();
+    let name = name.to_string();
+    let ty = type_name(ty);
+
+    quote!(#parameter::new(#name, #ty, #index))
+}
+
+/// Returns the expression constructing the description of a parameter that is not
+/// positional, used in errors.
+fn named_parameter(name: &Ident, ty: &Type) -> TokenStream {
+    let parameter = paths::parameter_type();
+    let name = name.to_string();
+    let ty = type_name(ty);
+
+    quote!(#parameter::named(#name, #ty))
+}
+
+/// Returns the type as written in the source, without the spacing of the token stream.
+fn type_name(ty: &Type) -> String {
+    let mut name = ty.to_token_stream().to_string();
+
+    for (from, to) in
+        &[(" < ", "<"), (" <", "<"), (" >", ">"), (" :: ", "::"), (" ,", ","), ("& ", "&")]
+    {
+        name = name.replace(from, to);
     }
 
-    Ok(())
+    name
+}
+
+/// Returns the identifier of the message parameter, which is passed to the argument parsers.
+///
+/// If the parameter is ignored with `_`, it is given an identifier.
+fn message_name(function: &mut ItemFn) -> Result<Ident> {
+    let arg = match function.sig.inputs.iter_mut().nth(1) {
+        Some(FnArg::Typed(arg)) => arg,
+        Some(arg) => return Err(Error::new(arg.span(), "`self` cannot be used as the message")),
+        None => return Err(Error::new(function.sig.span(), "missing message parameter")),
+    };
+
+    match &*arg.pat {
+        Pat::Ident(pat) => Ok(pat.ident.clone()),
+        Pat::Wild(_) => {
+            let name = format_ident!("__msg");
+            *arg.pat = parse2(quote!(#name))?;
+
+            Ok(name)
+        },
+        pat => Err(Error::new(pat.span(), "message parameter must have an identifier")),
+    }
 }
 
 /// Returns a result indicating whether the list of arguments is valid.
 ///
 /// Valid is defined as:
-/// - a list of arguments that have required arguments first,
-/// optional arguments second, and variadic arguments third; one or two of these
-/// types of arguments can be missing.
-/// - a list of arguments that only has one variadic argument parameter, if present.
-/// - a list of arguments that only has one rest argument parameter, if present.
+/// - a list of arguments that has a variadic argument or a rest argument last,
+///   if present; these consume every segment left.
 /// - a list of arguments that only has one variadic argument parameter or one rest
-/// argument parameter.
-fn check_arguments(args: &[Argument]) -> Result<()> {
+///   argument parameter.
+/// - a list of arguments that only has one set of arguments parameter, if present.
+///
+/// Optional and greedy arguments leave the segments they fail to parse to the
+/// next arguments, and can thus precede any argument.
+pub(crate) fn check_arguments(args: &[Argument]) -> Result<()> {
     let mut last_arg: Option<&Argument> = None;
 
     for arg in args {
         if let Some(last_arg) = last_arg {
             match (last_arg.kind, arg.kind) {
-                (ArgumentType::Optional, ArgumentType::Required) => {
+                (ArgumentType::Arguments, _) | (_, ArgumentType::Arguments) => {
                     return Err(Error::new(
-                        last_arg.name.span(),
-                        "optional argument cannot precede a required argument",
-                    ));
-                },
-                (ArgumentType::Variadic, ArgumentType::Required) => {
-                    return Err(Error::new(
-                        last_arg.name.span(),
-                        "variadic argument cannot precede a required argument",
-                    ));
-                },
-                (ArgumentType::Variadic, ArgumentType::Optional) => {
-                    return Err(Error::new(
-                        last_arg.name.span(),
-                        "variadic argument cannot precede an optional argument",
-                    ));
-                },
-                (ArgumentType::Rest, ArgumentType::Required) => {
-                    return Err(Error::new(
-                        last_arg.name.span(),
-                        "rest argument cannot precede a required argument",
-                    ));
-                },
-                (ArgumentType::Rest, ArgumentType::Optional) => {
-                    return Err(Error::new(
-                        last_arg.name.span(),
-                        "rest argument cannot precede an optional argument",
+                        arg.name.span(),
+                        "a set of arguments cannot be used alongside other positional arguments",
                     ));
                 },
                 (ArgumentType::Rest, ArgumentType::Variadic) => {
@@ -188,13 +382,21 @@ fn check_arguments(args: &[Argument]) -> Result<()> {
                         "a command cannot have two rest argument parameters",
                     ));
                 },
-                (ArgumentType::Required, ArgumentType::Required)
-                | (ArgumentType::Optional, ArgumentType::Optional)
-                | (ArgumentType::Required, ArgumentType::Optional)
-                | (ArgumentType::Required, ArgumentType::Variadic)
-                | (ArgumentType::Optional, ArgumentType::Variadic)
-                | (ArgumentType::Required, ArgumentType::Rest)
-                | (ArgumentType::Optional, ArgumentType::Rest) => {},
+                (ArgumentType::Variadic, _) => {
+                    return Err(Error::new(
+                        last_arg.name.span(),
+                        format!("variadic argument cannot precede {}", arg.description()),
+                    ));
+                },
+                (ArgumentType::Rest, _) => {
+                    return Err(Error::new(
+                        last_arg.name.span(),
+                        format!("rest argument cannot precede {}", arg.description()),
+                    ));
+                },
+                (ArgumentType::Required, _)
+                | (ArgumentType::Optional, _)
+                | (ArgumentType::Greedy, _) => {},
             };
         }
 
@@ -204,70 +406,605 @@ fn check_arguments(args: &[Argument]) -> Result<()> {
     Ok(())
 }
 
-struct Argument {
-    name: Ident,
-    ty: Box<Type>,
-    kind: ArgumentType,
+/// Reserves a segment to each required argument following a greedy argument.
+///
+/// Greedy arguments give back the segments they parsed until enough segments
+/// remain for the required arguments after them.
+pub(crate) fn reserve_segments(args: &mut [Argument]) {
+    for i in 0..args.len() {
+        if args[i].kind == ArgumentType::Greedy {
+            args[i].reserved =
+                args[i + 1..].iter().filter(|arg| arg.kind == ArgumentType::Required).count();
+        }
+    }
+}
+
+pub(crate) struct Argument {
+    pub(crate) name: Ident,
+    pub(crate) ty: Box<Type>,
+    pub(crate) kind: ArgumentType,
+    /// The type parsed through the parsing traits, without the `Option`, `Vec` or
+    /// `Greedy` wrapping it.
+    pub(crate) inner_ty: Type,
+    /// The value of the argument if it is absent, given by `#[default = ...]`.
+    pub(crate) default: Option<Expr>,
+    /// The custom parser of the argument, given by `#[parse_with(...)]`.
+    pub(crate) parser: Option<Path>,
+    /// The constraints on the value of the argument, given by `#[range(...)]` and the like.
+    pub(crate) constraints: Vec<Constraint>,
+    /// The bounds on the amount of values of the argument, given by `#[count(...)]`.
+    pub(crate) count: Option<Count>,
+    /// The amount of segments a greedy argument leaves to the arguments after it,
+    /// given by [`reserve_segments`].
+    pub(crate) reserved: usize,
+    pub(crate) index: usize,
 }
 
 impl Argument {
-    fn new(arg: FnArg) -> Result<Self> {
+    fn new(arg: FnArg, index: usize) -> Result<Self> {
         let binding = utils::get_pat_type(&arg)?;
 
         let name = utils::get_ident(&binding.pat)?;
 
-        let ty = binding.ty.clone();
+        Self::from_parts(name, binding.ty.clone(), &binding.attrs, index)
+    }
 
+    pub(crate) fn from_parts(
+        name: Ident,
+        ty: Box<Type>,
+        attrs: &[Attribute],
+        index: usize,
+    ) -> Result<Self> {
         let path = utils::get_path(&ty)?;
-        let kind = ArgumentType::new(&binding.attrs, path)?;
+        let mut kind = ArgumentType::new(path);
+        let mut default = None;
+        let mut parser = None;
+        let mut constraints = Vec::new();
+        let mut count = None;
+
+        for attr in attrs {
+            if let Some(constraint) = Constraint::parse(attr)? {
+                constraints.push(constraint);
+                continue;
+            }
+
+            let name = match attr.path.get_ident() {
+                Some(name) => name.to_string(),
+                None => String::new(),
+            };
+
+            match name.as_str() {
+                "rest" | "arguments" => {
+                    if !attr.tokens.is_empty() {
+                        return Err(Error::new(
+                            attr.span(),
+                            format!("the `{}` attribute does not accept any input", name),
+                        ));
+                    }
+
+                    if kind == ArgumentType::Rest || kind == ArgumentType::Arguments {
+                        return Err(Error::new(
+                            attr.span(),
+                            "an argument cannot be both a rest argument and a set of arguments",
+                        ));
+                    }
+
+                    kind =
+                        if name == "rest" { ArgumentType::Rest } else { ArgumentType::Arguments };
+                },
+                "default" => {
+                    if default.is_some() {
+                        return Err(Error::new(
+                            attr.span(),
+                            "an argument cannot have two default values",
+                        ));
+                    }
+
+                    default = Some(parse2::<DefaultValue>(attr.tokens.clone())?.0);
+                },
+                "parse_with" => {
+                    if parser.is_some() {
+                        return Err(Error::new(attr.span(), "an argument cannot have two parsers"));
+                    }
+
+                    parser = Some(attr.parse_args::<Path>()?);
+                },
+                "count" => {
+                    if count.is_some() {
+                        return Err(Error::new(attr.span(), "an argument cannot have two counts"));
+                    }
+
+                    count = Some(attr.parse_args::<Count>()?);
+                },
+                _ => {
+                    return Err(Error::new(
+                        attr.span(),
+                        "invalid attribute name, expected `rest`, `arguments`, `default`, \
+                         `parse_with`, `range`, `max_length`, `regex`, `count`, `flag`, `option` \
+                         or `implicit`",
+                    ));
+                },
+            }
+        }
+
+        if default.is_some() {
+            match kind {
+                ArgumentType::Required => kind = ArgumentType::Optional,
+                ArgumentType::Optional | ArgumentType::Variadic | ArgumentType::Greedy => {
+                    return Err(Error::new(
+                        ty.span(),
+                        "an argument with a default value cannot be an `Option`, a `Vec` or \
+                         a `Greedy`",
+                    ));
+                },
+                ArgumentType::Rest | ArgumentType::Arguments => {
+                    return Err(Error::new(
+                        ty.span(),
+                        "only single arguments can have a default value",
+                    ));
+                },
+            }
+        }
+
+        if parser.is_some() && kind == ArgumentType::Arguments {
+            return Err(Error::new(ty.span(), "a set of arguments cannot have a custom parser"));
+        }
+
+        if !constraints.is_empty() && kind == ArgumentType::Arguments {
+            return Err(Error::new(ty.span(), "a set of arguments cannot have constraints"));
+        }
+
+        if count.is_some() && kind != ArgumentType::Variadic && kind != ArgumentType::Greedy {
+            return Err(Error::new(
+                ty.span(),
+                "only variadic and greedy arguments can have a count",
+            ));
+        }
+
+        let inner_ty = match kind {
+            ArgumentType::Optional | ArgumentType::Variadic | ArgumentType::Greedy
+                if default.is_none() =>
+            {
+                utils::get_type_parameter(&ty)?
+            },
+            _ => (*ty).clone(),
+        };
 
         Ok(Self {
             name,
             ty,
             kind,
+            inner_ty,
+            default,
+            parser,
+            constraints,
+            count,
+            reserved: 0,
+            index,
         })
     }
+
+    /// Returns the expression constructing the description of the argument,
+    /// along with its constraints and choices.
+    ///
+    /// The choices are given by the `argument_trait` the argument is parsed through.
+    pub(crate) fn parameter(&self, argument_trait: &Path) -> TokenStream {
+        let mut parameter = parameter(&self.name, &self.ty, self.index);
+
+        if !self.constraints.is_empty() {
+            let constraints = self.constraints.iter().map(Constraint::metadata);
+            parameter = quote!(#parameter.with_constraints(&[#(#constraints),*]));
+        }
+
+        // Custom parsers and sets of arguments do not parse a type through the trait.
+        if self.parser.is_none() && self.kind != ArgumentType::Arguments {
+            let inner_ty = &self.inner_ty;
+            parameter = quote!(#parameter.with_choices(<#inner_ty as #argument_trait>::choices()));
+        }
+
+        parameter
+    }
+
+    /// Returns the statements parsing the argument out of the `segments` place
+    /// into a variable, and checking its constraints.
+    ///
+    /// Errors are converted by the `errors` method calls.
+    pub(crate) fn parsing(
+        &self,
+        context: Option<(&TokenStream, &TokenStream)>,
+        segments: &TokenStream,
+        errors: &TokenStream,
+    ) -> TokenStream {
+        let name = &self.name;
+        let ty = &self.ty;
+        let expr = self.parse_expr(context, &quote!(&mut #segments));
+        let default = self.default_value();
+
+        if self.constraints.is_empty() && self.count.is_none() {
+            return quote! {
+                let #name: #ty = #expr #errors? #default;
+            };
+        }
+
+        let count = self.count.as_ref().map(|count| {
+            let check = paths::check_count_func();
+            let min = count.min.as_ref().map_or_else(|| quote!(0), ToTokens::to_token_stream);
+            let max =
+                count.max.as_ref().map_or_else(|| quote!(usize::MAX), ToTokens::to_token_stream);
+
+            quote! {
+                #check(#name.len(), #min, #max, &#segments, __source, __start) #errors?;
+            }
+        });
+
+        // Every value of `Option` and `Vec` parameters is checked.
+        let values = match self.kind {
+            ArgumentType::Optional | ArgumentType::Variadic | ArgumentType::Greedy
+                if self.default.is_none() =>
+            {
+                quote!(#name.iter())
+            },
+            _ => quote!(std::iter::once(&#name)),
+        };
+
+        let check = paths::check_constraint_func();
+        let checks = self.constraints.iter().map(|constraint| {
+            let condition = constraint.condition(&quote!(__value));
+            let metadata = constraint.metadata();
+
+            quote! {
+                #check(#values.all(|__value| #condition), &#metadata, &#segments, __source, __start)
+                    #errors?;
+            }
+        });
+
+        quote! {
+            let __source = #segments.source();
+            let __start = #segments.offset();
+            let #name: #ty = #expr #errors? #default;
+            #count
+            #(#checks)*
+        }
+    }
+
+    /// Returns the description of the argument used in errors of [`check_arguments`].
+    fn description(&self) -> &'static str {
+        match self.kind {
+            ArgumentType::Required => "a required argument",
+            ArgumentType::Optional if self.default.is_some() => "an argument with a default value",
+            ArgumentType::Optional => "an optional argument",
+            ArgumentType::Variadic => "a variadic argument",
+            ArgumentType::Greedy => "a greedy argument",
+            ArgumentType::Rest => "a rest argument",
+            ArgumentType::Arguments => "a set of arguments",
+        }
+    }
+
+    /// Returns the expression parsing the argument out of the `segments`.
+    ///
+    /// Without a context, arguments are parsed through `FromSegments`, and the
+    /// parsing is completed at once.
+    pub(crate) fn parse_expr(
+        &self,
+        context: Option<(&TokenStream, &TokenStream)>,
+        segments: &TokenStream,
+    ) -> TokenStream {
+        let kind = self.kind;
+
+        let parser = match (&self.parser, context) {
+            (Some(parser), _) => {
+                let custom = paths::custom_parser_type();
+                quote!(#custom::new(#parser))
+            },
+            (None, Some((ctx, msg))) if kind == ArgumentType::Arguments => {
+                return quote!(#kind(#ctx, #msg, #segments).await);
+            },
+            (None, Some((ctx, msg))) => {
+                let parser = paths::argument_parser_type();
+                quote!(#parser::new(#ctx, #msg))
+            },
+            (None, None) => {
+                let parser = paths::segments_parser_type();
+                quote!(#parser::new())
+            },
+        };
+
+        let call = match kind {
+            ArgumentType::Greedy if self.reserved > 0 => {
+                let reserved_greedy = paths::reserved_greedy_arguments_func();
+                let reserved = self.reserved;
+                quote!(#reserved_greedy(#segments, #parser, #reserved))
+            },
+            _ => quote!(#kind(#segments, #parser)),
+        };
+
+        match context {
+            Some(_) => quote!(#call.await),
+            None => {
+                let complete = paths::complete_func();
+                quote!(#complete(#call))
+            },
+        }
+    }
+
+    /// Returns the method call replacing an absent argument with its default value.
+    pub(crate) fn default_value(&self) -> TokenStream {
+        match &self.default {
+            Some(default) => quote!(.unwrap_or_else(|| #default)),
+            None => TokenStream::new(),
+        }
+    }
 }
 
-#[derive(Clone, Copy)]
-enum ArgumentType {
-    Required,
-    Optional,
-    Variadic,
-    Rest,
+/// The value of a `#[default = ...]` attribute.
+struct DefaultValue(Expr);
+
+impl Parse for DefaultValue {
+    fn parse(input: ParseStream<'_>) -> Result<Self> {
+        input.parse::<Token![=]>()?;
+        input.parse().map(Self)
+    }
 }
 
-impl ArgumentType {
-    fn new(attrs: &[Attribute], path: &Path) -> Result<Self> {
-        if !attrs.is_empty() {
-            if attrs.len() > 1 {
-                return Err(Error::new(
-                    path.span(),
-                    "an argument cannot have more than 1 attribute",
-                ));
+/// The bounds of a `#[count(min = ..., max = ...)]` attribute.
+#[derive(Default)]
+pub(crate) struct Count {
+    min: Option<Expr>,
+    max: Option<Expr>,
+}
+
+impl Parse for Count {
+    fn parse(input: ParseStream<'_>) -> Result<Self> {
+        let mut count = Self::default();
+
+        while !input.is_empty() {
+            let name = input.parse::<Ident>()?;
+            input.parse::<Token![=]>()?;
+
+            let bound = match name.to_string().as_str() {
+                "min" => &mut count.min,
+                "max" => &mut count.max,
+                _ => return Err(Error::new(name.span(), "expected `min` or `max`")),
+            };
+
+            if bound.is_some() {
+                return Err(Error::new(name.span(), format!("`{}` is given twice", name)));
+            }
+
+            *bound = Some(input.parse()?);
+
+            if input.is_empty() {
+                break;
+            }
+
+            input.parse::<Token![,]>()?;
+        }
+
+        if count.min.is_none() && count.max.is_none() {
+            return Err(input.error("expected `min` or `max`"));
+        }
+
+        if let (Some(Expr::Lit(min)), Some(Expr::Lit(max))) = (&count.min, &count.max) {
+            if let (Lit::Int(min), Lit::Int(max)) = (&min.lit, &max.lit) {
+                if min.base10_parse::<u128>()? > max.base10_parse::<u128>()? {
+                    return Err(Error::new(max.span(), "`max` cannot be less than `min`"));
+                }
             }
+        }
+
+        Ok(count)
+    }
+}
 
-            let attr = utils::parse_attribute(&attrs[0])?;
+/// A parameter extracted by name from anywhere in the arguments.
+struct NamedArgument {
+    name: Ident,
+    ty: Box<Type>,
+    kind: NamedType,
+    /// The type of the value of the option, without the `Option` wrapping it.
+    inner_ty: Type,
+    long: String,
+    short: Option<LitChar>,
+}
 
-            if !attr.path.is_ident("rest") {
-                return Err(Error::new(attrs[0].span(), "invalid attribute name, expected `rest`"));
+impl NamedArgument {
+    /// Returns a named argument if the parameter is marked with `#[flag]` or `#[option]`.
+    fn new(arg: &FnArg) -> Result<Option<Self>> {
+        let binding = utils::get_pat_type(arg)?;
+
+        let attr = match binding
+            .attrs
+            .iter()
+            .find(|a| a.path.is_ident("flag") || a.path.is_ident("option"))
+        {
+            Some(attr) => attr,
+            None => return Ok(None),
+        };
+
+        if binding.attrs.len() > 1 {
+            return Err(Error::new(
+                binding.pat.span(),
+                "an argument cannot have more than 1 attribute",
+            ));
+        }
+
+        let name = utils::get_ident(&binding.pat)?;
+        let ty = binding.ty.clone();
+        let path = utils::get_path(&ty)?;
+        let last = path.segments.last().unwrap().ident.to_string();
+
+        let kind = if attr.path.is_ident("flag") {
+            if last != "bool" {
+                return Err(Error::new(ty.span(), "a flag must be of type `bool`"));
             }
 
-            if !attr.values.is_empty() {
-                return Err(Error::new(
-                    attrs[0].span(),
-                    "the `rest` attribute does not accept any input",
-                ));
+            NamedType::Flag
+        } else if last == "Option" {
+            NamedType::Optional
+        } else {
+            NamedType::Required
+        };
+
+        let inner_ty = match kind {
+            NamedType::Optional => utils::get_type_parameter(&ty)?,
+            _ => (*ty).clone(),
+        };
+
+        let mut long = name.to_string().trim_start_matches('_').replace('_', "-");
+        let mut short = None;
+
+        if let Meta::List(list) = attr.parse_meta()? {
+            for nested in list.nested {
+                match nested {
+                    NestedMeta::Meta(Meta::NameValue(nv)) if nv.path.is_ident("name") => {
+                        match nv.lit {
+                            Lit::Str(s) => long = s.value(),
+                            lit => return Err(Error::new(lit.span(), "name must be a string")),
+                        }
+                    },
+                    NestedMeta::Meta(Meta::NameValue(nv)) if nv.path.is_ident("short") => {
+                        match nv.lit {
+                            Lit::Char(c) => short = Some(c),
+                            lit => {
+                                return Err(Error::new(
+                                    lit.span(),
+                                    "short name must be a character",
+                                ))
+                            },
+                        }
+                    },
+                    nested => {
+                        return Err(Error::new(
+                            nested.span(),
+                            "invalid input, expected `name = \"...\"` or `short = '...'`",
+                        ))
+                    },
+                }
             }
+        }
 
-            return Ok(ArgumentType::Rest);
+        Ok(Some(Self {
+            name,
+            ty,
+            kind,
+            inner_ty,
+            long,
+            short,
+        }))
+    }
+
+    /// Returns the expression constructing the description of this argument, along
+    /// with the choices of options given by the `argument_trait`.
+    fn parameter(&self, argument_trait: &Path) -> TokenStream {
+        let parameter = named_parameter(&self.name, &self.ty);
+        let inner_ty = &self.inner_ty;
+
+        match self.kind {
+            NamedType::Flag => parameter,
+            _ => quote!(#parameter.with_choices(<#inner_ty as #argument_trait>::choices())),
         }
+    }
 
-        Ok(match path.segments.last().unwrap().ident.to_string().as_str() {
+    /// Returns the expression extracting the value of this argument.
+    fn value(&self, ctx_name: &Ident, msg_name: &Ident) -> TokenStream {
+        let long = &self.long;
+        let short = match &self.short {
+            Some(c) => quote!(::std::option::Option::Some(#c)),
+            None => quote!(::std::option::Option::None),
+        };
+
+        let func = match self.kind {
+            NamedType::Flag => return quote!(__named.flag(#long, #short)),
+            NamedType::Required => paths::required_option_func(),
+            NamedType::Optional => paths::optional_option_func(),
+        };
+
+        quote!(#func(&#ctx_name, #msg_name, &mut __named, #long, #short).await)
+    }
+}
+
+/// A parameter taken from the message invoking the command, rather than from
+/// its arguments.
+struct ImplicitArgument {
+    name: Ident,
+    ty: Box<Type>,
+    optional: bool,
+}
+
+impl ImplicitArgument {
+    /// Returns an implicit argument if the parameter is marked with `#[implicit]`.
+    fn new(arg: &FnArg) -> Result<Option<Self>> {
+        let binding = utils::get_pat_type(arg)?;
+
+        let attr = match binding.attrs.iter().find(|a| a.path.is_ident("implicit")) {
+            Some(attr) => attr,
+            None => return Ok(None),
+        };
+
+        if binding.attrs.len() > 1 {
+            return Err(Error::new(
+                binding.pat.span(),
+                "an implicit argument cannot have other attributes",
+            ));
+        }
+
+        if !attr.tokens.is_empty() {
+            return Err(Error::new(
+                attr.span(),
+                "the `implicit` attribute does not accept any input",
+            ));
+        }
+
+        let path = utils::get_path(&binding.ty)?;
+        let optional = path.segments.last().unwrap().ident == "Option";
+
+        Ok(Some(Self {
+            name: utils::get_ident(&binding.pat)?,
+            ty: binding.ty.clone(),
+            optional,
+        }))
+    }
+
+    /// Returns the expression taking the value of this argument out of the message.
+    fn value(&self, ctx_name: &Ident, msg_name: &Ident) -> TokenStream {
+        if self.optional {
+            let func = paths::optional_implicit_func();
+
+            return quote!(#func(#msg_name));
+        }
+
+        let func = paths::required_implicit_func();
+        let parameter = named_parameter(&self.name, &self.ty);
+
+        quote!(#func(&#ctx_name, #msg_name).map_err(|e| e.with_parameter(#parameter))?)
+    }
+}
+
+#[derive(Clone, Copy)]
+enum NamedType {
+    Flag,
+    Required,
+    Optional,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub(crate) enum ArgumentType {
+    Required,
+    Optional,
+    Variadic,
+    Greedy,
+    Rest,
+    Arguments,
+}
+
+impl ArgumentType {
+    fn new(path: &Path) -> Self {
+        match path.segments.last().unwrap().ident.to_string().as_str() {
             "Option" => ArgumentType::Optional,
             "Vec" => ArgumentType::Variadic,
+            "Greedy" => ArgumentType::Greedy,
             _ => ArgumentType::Required,
-        })
+        }
     }
 }
 
@@ -277,7 +1014,9 @@ impl ToTokens for ArgumentType {
             ArgumentType::Required => paths::required_argument_func(),
             ArgumentType::Optional => paths::optional_argument_func(),
             ArgumentType::Variadic => paths::variadic_arguments_func(),
+            ArgumentType::Greedy => paths::greedy_arguments_func(),
             ArgumentType::Rest => paths::rest_argument_func(),
+            ArgumentType::Arguments => paths::parse_arguments_func(),
         };
 
         tokens.extend(quote!(#path));
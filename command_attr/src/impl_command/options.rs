@@ -4,6 +4,7 @@ use proc_macro2::{Ident, TokenStream};
 use quote::{quote, ToTokens};
 use syn::{Attribute, Result};
 
+use crate::paths;
 use crate::utils::{parse_bool, parse_identifier, parse_identifiers, parse_string};
 
 #[derive(Default)]
@@ -16,7 +17,10 @@ pub struct Options {
     examples: Vec<String>,
     dynamic_examples: Option<Ident>,
     help_available: Option<bool>,
-    check: Option<Ident>,
+    checks: Vec<Ident>,
+    check_inheritance: Option<Ident>,
+    bucket: Option<String>,
+    pub strict_arguments: Option<bool>,
     pub delimiter: Option<String>,
 }
 
@@ -59,7 +63,14 @@ impl Options {
                     options.dynamic_examples = Some(parse_identifier(&attr.try_into()?)?)
                 },
                 "help_available" => options.help_available = Some(parse_bool(&attr.try_into()?)?),
-                "check" => options.check = Some(parse_identifier(&attr.try_into()?)?),
+                "check" => options.checks.extend(parse_identifiers(&attr.try_into()?)?),
+                "check_inheritance" => {
+                    options.check_inheritance = Some(parse_identifier(&attr.try_into()?)?)
+                },
+                "bucket" => options.bucket = Some(parse_string(&attr.try_into()?)?),
+                "strict_arguments" => {
+                    options.strict_arguments = Some(parse_bool(&attr.try_into()?)?)
+                },
                 "delimiter" => options.delimiter = Some(parse_string(&attr.try_into()?)?),
                 _ => {
                     i += 1;
@@ -86,7 +97,10 @@ impl ToTokens for Options {
             examples,
             dynamic_examples,
             help_available,
-            check,
+            checks,
+            check_inheritance,
+            bucket,
+            strict_arguments,
             ..
         } = self;
 
@@ -122,8 +136,22 @@ impl ToTokens for Options {
             tokens.extend(quote!(.help_available(#help_available)));
         }
 
-        if let Some(check) = check {
-            tokens.extend(quote!(.check(#check)));
+        tokens.extend(quote! {
+            #(.check(#checks))*
+        });
+
+        if let Some(inheritance) = check_inheritance {
+            let inheritance_type = paths::check_inheritance_type();
+
+            tokens.extend(quote!(.check_inheritance(#inheritance_type::#inheritance)));
+        }
+
+        if let Some(bucket) = bucket {
+            tokens.extend(quote!(.bucket(#bucket)));
+        }
+
+        if let Some(strict_arguments) = strict_arguments {
+            tokens.extend(quote!(.strict_arguments(#strict_arguments)));
         }
     }
 }
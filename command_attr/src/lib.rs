@@ -3,11 +3,15 @@ use proc_macro::TokenStream;
 mod paths;
 mod utils;
 
+mod impl_arguments;
 mod impl_check;
+mod impl_choice;
 mod impl_command;
 mod impl_hook;
 
+use impl_arguments::{impl_arguments, impl_from_segments};
 use impl_check::impl_check;
+use impl_choice::impl_choice;
 use impl_command::impl_command;
 use impl_hook::impl_hook;
 
@@ -34,3 +38,33 @@ pub fn hook(attr: TokenStream, input: TokenStream) -> TokenStream {
         Err(err) => err.to_compile_error().into(),
     }
 }
+
+#[proc_macro_derive(
+    Arguments,
+    attributes(rest, default, parse_with, range, max_length, regex, count)
+)]
+pub fn arguments(input: TokenStream) -> TokenStream {
+    match impl_arguments(input.into()) {
+        Ok(stream) => stream.into(),
+        Err(err) => err.to_compile_error().into(),
+    }
+}
+
+#[proc_macro_derive(
+    FromSegments,
+    attributes(rest, default, parse_with, range, max_length, regex, count)
+)]
+pub fn from_segments(input: TokenStream) -> TokenStream {
+    match impl_from_segments(input.into()) {
+        Ok(stream) => stream.into(),
+        Err(err) => err.to_compile_error().into(),
+    }
+}
+
+#[proc_macro_derive(Choice, attributes(choice))]
+pub fn choice(input: TokenStream) -> TokenStream {
+    match impl_choice(input.into()) {
+        Ok(stream) => stream.into(),
+        Err(err) => err.to_compile_error().into(),
+    }
+}
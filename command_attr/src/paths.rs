@@ -46,6 +46,18 @@ pub fn argument_segments_type() -> Path {
     })
 }
 
+pub fn named_arguments_type() -> Path {
+    to_path(quote! {
+        serenity_framework::argument::NamedArguments
+    })
+}
+
+pub fn parameter_type() -> Path {
+    to_path(quote! {
+        serenity_framework::argument::Parameter
+    })
+}
+
 pub fn required_argument_func() -> Path {
     to_path(quote! {
         serenity_framework::argument::required_argument
@@ -64,12 +76,156 @@ pub fn variadic_arguments_func() -> Path {
     })
 }
 
+pub fn greedy_arguments_func() -> Path {
+    to_path(quote! {
+        serenity_framework::argument::greedy_arguments
+    })
+}
+
+pub fn reserved_greedy_arguments_func() -> Path {
+    to_path(quote! {
+        serenity_framework::argument::reserved_greedy_arguments
+    })
+}
+
 pub fn rest_argument_func() -> Path {
     to_path(quote! {
         serenity_framework::argument::rest_argument
     })
 }
 
+pub fn parse_arguments_func() -> Path {
+    to_path(quote! {
+        serenity_framework::argument::Arguments::parse_arguments
+    })
+}
+
+pub fn required_option_func() -> Path {
+    to_path(quote! {
+        serenity_framework::argument::required_option
+    })
+}
+
+pub fn optional_option_func() -> Path {
+    to_path(quote! {
+        serenity_framework::argument::optional_option
+    })
+}
+
+pub fn message_type() -> Path {
+    to_path(quote! {
+        serenity::model::channel::Message
+    })
+}
+
+pub fn context_type(data: &Type, error: &Type) -> Path {
+    to_path(quote! {
+        serenity_framework::context::Context<#data, #error>
+    })
+}
+
+pub fn argument_trait(data: &Type, error: &Type) -> Path {
+    to_path(quote! {
+        serenity_framework::argument::Argument<#data, #error>
+    })
+}
+
+pub fn arguments_trait(data: &Type, error: &Type) -> Path {
+    to_path(quote! {
+        serenity_framework::argument::Arguments<#data, #error>
+    })
+}
+
+pub fn from_segments_trait() -> Path {
+    to_path(quote! {
+        serenity_framework::argument::FromSegments
+    })
+}
+
+pub fn argument_error_type() -> Path {
+    to_path(quote! {
+        serenity_framework::argument::ArgumentError
+    })
+}
+
+pub fn boxed_error_type() -> Path {
+    to_path(quote! {
+        serenity_framework::argument::BoxedError
+    })
+}
+
+pub fn choice_trait() -> Path {
+    to_path(quote! {
+        serenity_framework::argument::Choice
+    })
+}
+
+pub fn argument_parser_type() -> Path {
+    to_path(quote! {
+        serenity_framework::argument::ArgumentParser
+    })
+}
+
+pub fn segments_parser_type() -> Path {
+    to_path(quote! {
+        serenity_framework::argument::SegmentsParser
+    })
+}
+
+pub fn complete_func() -> Path {
+    to_path(quote! {
+        serenity_framework::argument::parser::complete
+    })
+}
+
+pub fn custom_parser_type() -> Path {
+    to_path(quote! {
+        serenity_framework::argument::custom::CustomParser
+    })
+}
+
+pub fn constraint_type() -> Path {
+    to_path(quote! {
+        serenity_framework::argument::Constraint
+    })
+}
+
+pub fn regex_type() -> Path {
+    to_path(quote! {
+        serenity_framework::regex::Regex
+    })
+}
+
+pub fn check_constraint_func() -> Path {
+    to_path(quote! {
+        serenity_framework::argument::constraint::check_constraint
+    })
+}
+
+pub fn check_extraneous_func() -> Path {
+    to_path(quote! {
+        serenity_framework::argument::check_extraneous
+    })
+}
+
+pub fn check_count_func() -> Path {
+    to_path(quote! {
+        serenity_framework::argument::check_count
+    })
+}
+
+pub fn required_implicit_func() -> Path {
+    to_path(quote! {
+        serenity_framework::argument::implicit::required_implicit
+    })
+}
+
+pub fn optional_implicit_func() -> Path {
+    to_path(quote! {
+        serenity_framework::argument::implicit::optional_implicit
+    })
+}
+
 pub fn check_type(data: &Type, error: &Type) -> Path {
     to_path(quote! {
         serenity_framework::check::Check<#data, #error>
@@ -81,3 +237,9 @@ pub fn check_builder_type() -> Path {
         serenity_framework::check::CheckBuilder
     })
 }
+
+pub fn check_inheritance_type() -> Path {
+    to_path(quote! {
+        serenity_framework::check::Inheritance
+    })
+}
@@ -222,6 +222,19 @@ pub fn get_path(t: &Type) -> Result<&Path> {
     }
 }
 
+/// Returns the first type parameter of the type, such as `T` in `Option<T>`.
+pub fn get_type_parameter(t: &Type) -> Result<Type> {
+    let path = get_path(t)?;
+
+    match &path.segments.last().unwrap().arguments {
+        PathArguments::AngleBracketed(args) => match args.args.first() {
+            Some(GenericArgument::Type(ty)) => Ok(ty.clone()),
+            _ => Err(Error::new(args.span(), "expected a type parameter")),
+        },
+        _ => Err(Error::new(t.span(), "expected a type parameter")),
+    }
+}
+
 fn get_generic_arguments(path: &Path) -> Result<impl Iterator<Item = &GenericArgument> + '_> {
     match &path.segments.last().unwrap().arguments {
         PathArguments::None => Ok(Vec::new().into_iter()),
@@ -0,0 +1,114 @@
+//! Parsing of arguments out of a fixed set of choices.
+//!
+//! Fieldless enums deriving the `Choice` macro are parsed from the names of
+//! their variants, or from aliases given with `#[choice(alias = "...")]`.
+//! Names are compared case-insensitively, and default to the name of the
+//! variant in lowercase. A different name is given with `#[choice(name = "...")]`.
+//! A name cannot be given to more than one variant.
+//!
+//! The names of the choices are listed in the help of commands taking them,
+//! through the [choices] of their parameters.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use serenity_framework::argument::choice::Choice;
+//! use serenity_framework::argument::FromSegments;
+//! use serenity_framework::utils::ArgumentSegments;
+//!
+//! #[derive(Debug, PartialEq, Choice)]
+//! enum Toggle {
+//!     #[choice(alias = "yes", alias = "enable")]
+//!     On,
+//!     #[choice(alias = "no", alias = "disable")]
+//!     Off,
+//! }
+//!
+//! assert_eq!(Toggle::CHOICES, &["on", "off"]);
+//! assert_eq!(Toggle::On.name(), "on");
+//!
+//! let mut segments = ArgumentSegments::new("ON enable maybe", " ");
+//!
+//! assert_eq!(Toggle::from_segments(&mut segments).unwrap(), Toggle::On);
+//! assert_eq!(Toggle::from_segments(&mut segments).unwrap(), Toggle::On);
+//!
+//! let err = Toggle::from_segments(&mut segments).unwrap_err();
+//! assert_eq!(err.to_string(), "expected one of `on`, `off`");
+//! ```
+//!
+//! [choices]: crate::argument::Parameter::choices
+
+use std::error::Error as StdError;
+use std::fmt;
+
+#[cfg(feature = "macros")]
+pub use command_attr::Choice;
+
+use super::FromSegments;
+use crate::utils::ArgumentSegments;
+
+/// A type whose values are chosen by name out of a fixed set.
+///
+/// This is implemented by fieldless enums deriving the `Choice` macro. Refer
+/// to the [module-level documentation][docs].
+///
+/// [docs]: crate::argument::choice
+pub trait Choice: Sized {
+    /// The names of the choices, in the order of the variants.
+    ///
+    /// Aliases are not included. This list is meant to be displayed to users,
+    /// such as in the usage of a command.
+    const CHOICES: &'static [&'static str];
+
+    /// Returns the choice named by the name or an alias, compared case-insensitively.
+    fn from_name(name: &str) -> Option<Self>;
+
+    /// Returns the name of this choice.
+    fn name(&self) -> &'static str;
+
+    /// Returns the names of the choices separated by `|`, for usage strings.
+    fn usage() -> String {
+        Self::CHOICES.join("|")
+    }
+}
+
+/// Error that occurs when an argument does not name any of the choices.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InvalidChoice {
+    /// The names of the valid choices.
+    pub choices: &'static [&'static str],
+}
+
+impl fmt::Display for InvalidChoice {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("expected one of ")?;
+
+        for (i, choice) in self.choices.iter().enumerate() {
+            if i > 0 {
+                f.write_str(", ")?;
+            }
+
+            write!(f, "`{}`", choice)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl StdError for InvalidChoice {}
+
+impl<T: Choice> FromSegments for T {
+    type Error = InvalidChoice;
+
+    fn from_segments(segments: &mut ArgumentSegments<'_>) -> Result<Self, Self::Error> {
+        let segment = segments.next().unwrap_or_default();
+
+        T::from_name(&segment).ok_or(InvalidChoice {
+            choices: T::CHOICES,
+        })
+    }
+
+    fn choices() -> &'static [&'static str] {
+        T::CHOICES
+    }
+}
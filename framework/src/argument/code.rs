@@ -0,0 +1,162 @@
+//! Parsing of code blocks.
+//!
+//! A [`CodeBlock`] is parsed out of a fenced code block, with an optional language:
+//!
+//! ````text
+//! ```rust
+//! println!("Hello, world!");
+//! ```
+//! ````
+//!
+//! or out of inline code, such as `` `1 + 1` `` or ``` `` `backticks` `` ```.
+//!
+//! The code is taken verbatim from the arguments, rather than split into
+//! segments. Code blocks may thus be used as positional arguments, as well
+//! as [rest arguments][rest].
+//!
+//! # Examples
+//!
+//! ```rust
+//! use serenity_framework::argument::code::CodeBlock;
+//! use serenity_framework::argument::FromSegments;
+//! use serenity_framework::utils::ArgumentSegments;
+//!
+//! let mut segments = ArgumentSegments::new("```rs\nlet x = \"a b\";\n``` `x + 1` rest", " ");
+//!
+//! let block = CodeBlock::from_segments(&mut segments).unwrap();
+//! assert_eq!(block.language.as_deref(), Some("rs"));
+//! assert_eq!(block.code, "let x = \"a b\";");
+//!
+//! let inline = CodeBlock::from_segments(&mut segments).unwrap();
+//! assert_eq!(inline.language, None);
+//! assert_eq!(inline.code, "x + 1");
+//!
+//! assert!(CodeBlock::from_segments(&mut segments).is_err());
+//! assert!(segments.is_empty());
+//! ```
+//!
+//! [rest]: super::rest_argument
+
+use std::error::Error as StdError;
+use std::fmt;
+
+use super::FromSegments;
+use crate::utils::ArgumentSegments;
+
+/// Error that occurs when an argument is not a code block.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InvalidCodeBlock;
+
+impl fmt::Display for InvalidCodeBlock {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("expected a code block or inline code")
+    }
+}
+
+impl StdError for InvalidCodeBlock {}
+
+/// A code block or inline code.
+///
+/// Refer to the [module-level documentation][docs].
+///
+/// [docs]: crate::argument::code
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct CodeBlock {
+    /// The language of the code, given after the opening fence of a code block.
+    pub language: Option<String>,
+    /// The code, without the fences.
+    pub code: String,
+}
+
+impl CodeBlock {
+    /// Parses a code block at the start of the source, returning the source after it.
+    ///
+    /// Whitespace before the code block is ignored.
+    pub fn parse_prefix(src: &str) -> Option<(Self, &str)> {
+        let src = src.trim_start();
+
+        if let Some(inner) = src.strip_prefix("```") {
+            let end = inner.find("```")?;
+            let body = &inner[..end];
+
+            // The language is only given on the first line of a code block,
+            // and cannot contain any whitespace.
+            let (language, code) = match body.split_once('\n') {
+                Some((language, code)) if !language.trim_end().contains(char::is_whitespace) => {
+                    (Some(language.trim_end()).filter(|l| !l.is_empty()), code)
+                },
+                _ => (None, body),
+            };
+
+            let code = code.strip_suffix('\n').unwrap_or(code);
+
+            if code.is_empty() {
+                return None;
+            }
+
+            let block = Self {
+                language: language.map(ToString::to_string),
+                code: code.to_string(),
+            };
+
+            return Some((block, &inner[(end + 3)..]));
+        }
+
+        let fence = if src.starts_with("``") {
+            "``"
+        } else if src.starts_with('`') {
+            "`"
+        } else {
+            return None;
+        };
+
+        let inner = &src[fence.len()..];
+        let end = inner.find(fence)?;
+        let mut code = &inner[..end];
+
+        // Double backticks allow the code to start or end with a backtick,
+        // separated from the fence by a space.
+        if fence.len() == 2 {
+            code = code.strip_prefix(' ').unwrap_or(code);
+            code = code.strip_suffix(' ').unwrap_or(code);
+        }
+
+        if code.is_empty() {
+            return None;
+        }
+
+        let block = Self {
+            language: None,
+            code: code.to_string(),
+        };
+
+        Some((block, &inner[(end + fence.len())..]))
+    }
+}
+
+impl FromSegments for CodeBlock {
+    type Error = InvalidCodeBlock;
+
+    fn from_segments(segments: &mut ArgumentSegments<'_>) -> Result<Self, Self::Error> {
+        match Self::parse_prefix(segments.source()) {
+            Some((block, rest)) => {
+                let delimiter = segments.delimiter();
+
+                if delimiter.is_empty() {
+                    segments.set_source(rest);
+                } else {
+                    segments.set_source(rest.trim_start_matches(delimiter));
+                }
+
+                Ok(block)
+            },
+            None => {
+                // Consume the segment to describe it in the error.
+                segments.next();
+
+                Err(InvalidCodeBlock)
+            },
+        }
+    }
+}
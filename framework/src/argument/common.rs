@@ -0,0 +1,254 @@
+//! Parsing of common values.
+//!
+//! The following types are supported:
+//!
+//! - [`Duration`]: a sequence of amounts and units, such as `1h30m`, `2d` or `90s`.
+//!   Units are `w`, `d`, `h`, `m` and `s`, or their names, such as `hours`.
+//! - [`DateTime<Utc>`]: a Discord timestamp, such as `<t:1618953630>` or `<t:1618953630:R>`,
+//!   or an ISO 8601 date, such as `2021-04-20`, `2021-04-20T21:20` or
+//!   `2021-04-20T21:20:30+02:00`. Dates without an offset are in UTC.
+//! - [`Colour`]: a hexadecimal colour, such as `#ff00aa`, `ff00aa`, `0xff00aa` or `#f0a`,
+//!   or an RGB colour, such as `255,0,170` or `rgb(255,0,170)`.
+//! - [`bool`]: `true`, `yes`, `y`, `on`, `enable` or `1`, and `false`, `no`, `n`,
+//!   `off`, `disable` or `0`.
+//!
+//! Units and booleans are compared case-insensitively.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use std::time::Duration;
+//!
+//! use chrono::{DateTime, Utc};
+//! use serenity::utils::Colour;
+//! use serenity_framework::argument::FromSegments;
+//! use serenity_framework::utils::ArgumentSegments;
+//!
+//! let mut segments = ArgumentSegments::new("1h30m #ff00aa off", " ");
+//!
+//! assert_eq!(Duration::from_segments(&mut segments).unwrap(), Duration::from_secs(5400));
+//! assert_eq!(Colour::from_segments(&mut segments).unwrap(), Colour::new(0xff00aa));
+//! assert_eq!(bool::from_segments(&mut segments).unwrap(), false);
+//!
+//! let mut segments = ArgumentSegments::new("<t:1618953630:R> 2021-04-20 rgb(255,0,170) 2y", " ");
+//!
+//! let timestamp = DateTime::<Utc>::from_segments(&mut segments).unwrap();
+//! assert_eq!(timestamp.timestamp(), 1618953630);
+//!
+//! let date = DateTime::<Utc>::from_segments(&mut segments).unwrap();
+//! assert_eq!(date.to_rfc3339(), "2021-04-20T00:00:00+00:00");
+//!
+//! assert_eq!(Colour::from_segments(&mut segments).unwrap(), Colour::from_rgb(255, 0, 170));
+//!
+//! let err = Duration::from_segments(&mut segments).unwrap_err();
+//! assert_eq!(err.to_string(), "unknown unit of time `y`");
+//! ```
+//!
+//! [`Duration`]: std::time::Duration
+//! [`DateTime<Utc>`]: chrono::DateTime
+//! [`Colour`]: serenity::utils::Colour
+
+use std::error::Error as StdError;
+use std::fmt;
+use std::time::Duration;
+
+use chrono::{DateTime, NaiveDate, NaiveDateTime, TimeZone, Utc};
+use serenity::utils::Colour;
+
+use super::FromSegments;
+use crate::utils::ArgumentSegments;
+
+/// Error that might occur when parsing a duration.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum DurationError {
+    /// The argument is not a sequence of amounts and units.
+    Invalid,
+    /// A unit of time is not recognised.
+    UnknownUnit(String),
+    /// The duration is too long to be represented.
+    Overflow,
+}
+
+impl fmt::Display for DurationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DurationError::Invalid => f.write_str("expected a duration, such as `1h30m`"),
+            DurationError::UnknownUnit(unit) => write!(f, "unknown unit of time `{}`", unit),
+            DurationError::Overflow => f.write_str("duration is too long"),
+        }
+    }
+}
+
+impl StdError for DurationError {}
+
+/// Error that occurs when an argument is not a timestamp.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InvalidTimestamp;
+
+impl fmt::Display for InvalidTimestamp {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("expected a Discord timestamp or a date, such as `2021-04-20`")
+    }
+}
+
+impl StdError for InvalidTimestamp {}
+
+/// Error that occurs when an argument is not a colour.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InvalidColour;
+
+impl fmt::Display for InvalidColour {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("expected a colour, such as `#ff00aa` or `255,0,170`")
+    }
+}
+
+impl StdError for InvalidColour {}
+
+/// Error that occurs when an argument is not a boolean.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InvalidBool;
+
+impl fmt::Display for InvalidBool {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("expected `yes` or `no`")
+    }
+}
+
+impl StdError for InvalidBool {}
+
+/// Returns the amount of seconds in a unit of time.
+fn unit_seconds(unit: &str) -> Option<u64> {
+    let seconds = match unit.to_ascii_lowercase().as_str() {
+        "s" | "sec" | "secs" | "second" | "seconds" => 1,
+        "m" | "min" | "mins" | "minute" | "minutes" => 60,
+        "h" | "hr" | "hrs" | "hour" | "hours" => 60 * 60,
+        "d" | "day" | "days" => 24 * 60 * 60,
+        "w" | "week" | "weeks" => 7 * 24 * 60 * 60,
+        _ => return None,
+    };
+
+    Some(seconds)
+}
+
+fn parse_duration(s: &str) -> Result<Duration, DurationError> {
+    if s.is_empty() {
+        return Err(DurationError::Invalid);
+    }
+
+    let mut seconds: u64 = 0;
+    let mut rest = s;
+
+    while !rest.is_empty() {
+        let digits = rest.find(|c: char| !c.is_ascii_digit()).unwrap_or(rest.len());
+        let unit = rest[digits..].find(|c: char| c.is_ascii_digit()).unwrap_or(rest.len() - digits);
+
+        if digits == 0 || unit == 0 {
+            return Err(DurationError::Invalid);
+        }
+
+        let amount = rest[..digits].parse::<u64>().map_err(|_| DurationError::Overflow)?;
+        let unit = &rest[digits..(digits + unit)];
+        let unit_seconds =
+            unit_seconds(unit).ok_or_else(|| DurationError::UnknownUnit(unit.to_string()))?;
+
+        seconds = amount
+            .checked_mul(unit_seconds)
+            .and_then(|s| seconds.checked_add(s))
+            .ok_or(DurationError::Overflow)?;
+
+        rest = &rest[(digits + unit.len())..];
+    }
+
+    Ok(Duration::from_secs(seconds))
+}
+
+fn parse_timestamp(s: &str) -> Result<DateTime<Utc>, InvalidTimestamp> {
+    if let Some(inner) = s.strip_prefix("<t:").and_then(|s| s.strip_suffix('>')) {
+        let seconds = match inner.split_once(':') {
+            Some((seconds, style)) if style.len() == 1 => seconds,
+            Some(_) => return Err(InvalidTimestamp),
+            None => inner,
+        };
+
+        let seconds = seconds.parse::<i64>().map_err(|_| InvalidTimestamp)?;
+
+        return Utc.timestamp_opt(seconds, 0).single().ok_or(InvalidTimestamp);
+    }
+
+    if let Ok(date) = DateTime::parse_from_rfc3339(s) {
+        return Ok(date.with_timezone(&Utc));
+    }
+
+    for format in &["%Y-%m-%dT%H:%M:%S", "%Y-%m-%dT%H:%M", "%Y-%m-%d %H:%M"] {
+        if let Ok(date) = NaiveDateTime::parse_from_str(s, format) {
+            return Ok(Utc.from_utc_datetime(&date));
+        }
+    }
+
+    let date = NaiveDate::parse_from_str(s, "%Y-%m-%d").map_err(|_| InvalidTimestamp)?;
+    let date = date.and_hms_opt(0, 0, 0).ok_or(InvalidTimestamp)?;
+
+    Ok(Utc.from_utc_datetime(&date))
+}
+
+fn parse_colour(s: &str) -> Result<Colour, InvalidColour> {
+    let rgb = s.strip_prefix("rgb(").and_then(|s| s.strip_suffix(')')).unwrap_or(s);
+
+    if rgb.contains(',') {
+        let mut channels = rgb.split(',').map(|c| c.trim().parse::<u8>());
+
+        return match (channels.next(), channels.next(), channels.next(), channels.next()) {
+            (Some(Ok(r)), Some(Ok(g)), Some(Ok(b)), None) => Ok(Colour::from_rgb(r, g, b)),
+            _ => Err(InvalidColour),
+        };
+    }
+
+    let hex = s.strip_prefix('#').or_else(|| s.strip_prefix("0x")).unwrap_or(s);
+
+    if !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+        return Err(InvalidColour);
+    }
+
+    let value = match hex.len() {
+        6 => u32::from_str_radix(hex, 16).map_err(|_| InvalidColour)?,
+        // Expand the shorthand `#rgb` into `#rrggbb`.
+        3 => hex.chars().fold(0, |value, c| {
+            let digit = c.to_digit(16).unwrap();
+            (value << 8) | (digit << 4) | digit
+        }),
+        _ => return Err(InvalidColour),
+    };
+
+    Ok(Colour::new(value))
+}
+
+fn parse_bool(s: &str) -> Result<bool, InvalidBool> {
+    match s.to_ascii_lowercase().as_str() {
+        "true" | "yes" | "y" | "on" | "enable" | "1" => Ok(true),
+        "false" | "no" | "n" | "off" | "disable" | "0" => Ok(false),
+        _ => Err(InvalidBool),
+    }
+}
+
+macro_rules! impl_argument {
+    ($($ty:ty => $parse:ident, $err:ty;)*) => {
+        $(
+            impl FromSegments for $ty {
+                type Error = $err;
+
+                fn from_segments(segments: &mut ArgumentSegments<'_>) -> Result<Self, Self::Error> {
+                    $parse(&segments.next().unwrap_or_default())
+                }
+            }
+        )*
+    };
+}
+
+impl_argument! {
+    Duration => parse_duration, DurationError;
+    DateTime<Utc> => parse_timestamp, InvalidTimestamp;
+    Colour => parse_colour, InvalidColour;
+    bool => parse_bool, InvalidBool;
+}
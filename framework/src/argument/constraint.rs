@@ -0,0 +1,175 @@
+//! Constraints on the values of arguments.
+//!
+//! Parameters of commands are constrained with the following attributes:
+//!
+//! - `#[range(1..=100)]`: the value must be within the range. Any range syntax is
+//!   accepted, such as `1..`, `..10` or `0.0..1.0`.
+//! - `#[max_length(200)]`: the value must be at most this many characters long.
+//!   The type of the parameter must implement `AsRef<str>`.
+//! - `#[regex("^[a-z]+$")]`: the value must match the regular expression, as
+//!   understood by the [`regex`] crate. The type of the parameter must implement
+//!   `AsRef<str>`. Invalid patterns are rejected at compile time.
+//!
+//! Constraints apply to every value of `Option` and `Vec` parameters. If a value
+//! violates a constraint, parsing fails with [`ArgumentErrorKind::Constraint`].
+//! Values are compared to the bounds of ranges in the type of the parameter.
+//!
+//! The constraints of a parameter are available in its [description][parameter],
+//! to be displayed in help or registered with slash commands.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use serenity::model::channel::Message;
+//! use serenity_framework::prelude::*;
+//!
+//! #[command]
+//! async fn roll(
+//!     _ctx: FrameworkContext,
+//!     _msg: &Message,
+//!     #[range(1..=100)] sides: u32,
+//!     #[regex("^[a-z]+$")]
+//!     #[max_length(8)]
+//!     label: Option<String>,
+//! ) -> CommandResult {
+//!     assert_eq!(sides, 20);
+//!     assert_eq!(label.as_deref(), Some("attack"));
+//!     Ok(())
+//! }
+//!
+//! # async fn dispatch<D: Send + Sync, E: std::fmt::Display>(
+//! #     framework: &Framework<D, E>,
+//! #     content: &str,
+//! # ) -> Result<(), String> {
+//! #     use std::sync::Arc;
+//! #     use serenity::prelude::{RwLock, TypeMap};
+//! #     let ctx = serenity::client::Context {
+//! #         data: Arc::new(RwLock::new(TypeMap::new())),
+//! #         shard: serenity::client::bridge::gateway::ShardMessenger::new(
+//! #             serenity::futures::channel::mpsc::unbounded().0,
+//! #         ),
+//! #         shard_id: 0,
+//! #         http: Arc::new(serenity::http::Http::new_with_token("")),
+//! #         cache: Arc::new(serenity::cache::Cache::new()),
+//! #     };
+//! #     let msg = serde_json::from_value(serde_json::json!({
+//! #         "id": "1", "channel_id": "1", "type": 0, "content": content,
+//! #         "author": { "id": "1", "username": "user", "discriminator": "0001", "avatar": null },
+//! #         "attachments": [], "embeds": [], "mentions": [], "mention_roles": [],
+//! #         "mention_everyone": false, "pinned": false, "tts": false,
+//! #         "timestamp": "2021-01-01T00:00:00Z",
+//! #     }));
+//! #     framework.dispatch(&ctx, &msg.unwrap()).await.map_err(|e| e.to_string())
+//! # }
+//! # #[tokio::main(flavor = "current_thread")]
+//! # async fn main() {
+//! let mut conf: Configuration = Configuration::new();
+//! conf.prefix("!").command(roll);
+//! let framework: Framework = Framework::new(conf);
+//!
+//! // `dispatch` invokes the framework with a message, returning its error as text.
+//! assert_eq!(dispatch(&framework, "!roll 20 attack").await, Ok(()));
+//!
+//! let err = dispatch(&framework, "!roll 200 attack").await.unwrap_err();
+//! assert_eq!(err, "argument 1 (`sides`): `200`: must be between 1 and 100");
+//!
+//! let err = dispatch(&framework, "!roll 20 Attack").await.unwrap_err();
+//! assert_eq!(err, "argument 2 (`label`): `Attack`: must match the pattern `^[a-z]+$`");
+//!
+//! let err = dispatch(&framework, "!roll 20 counterattack").await.unwrap_err();
+//! assert_eq!(err, "argument 2 (`label`): `counterattack`: must be at most 8 characters long");
+//! # }
+//! ```
+//!
+//! The constraints are described in errors and help:
+//!
+//! ```rust
+//! use std::ops::Bound;
+//!
+//! use serenity_framework::argument::Constraint;
+//!
+//! let range = Constraint::Range {
+//!     start: Bound::Included("1"),
+//!     end: Bound::Included("100"),
+//! };
+//!
+//! assert_eq!(range.to_string(), "must be between 1 and 100");
+//! assert_eq!(Constraint::MaxLength(200).to_string(), "must be at most 200 characters long");
+//! ```
+//!
+//! [`regex`]: https://docs.rs/regex
+//! [parameter]: super::Parameter::constraints
+
+use std::convert::Infallible;
+use std::fmt;
+use std::ops::Bound;
+
+use super::{consumed_input, ArgumentError, ArgumentErrorKind};
+use crate::utils::ArgumentSegments;
+
+/// A constraint on the value of an argument.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Constraint {
+    /// The value must be within a range.
+    ///
+    /// The bounds are written as in the attribute, such as `1` or `i64::MAX`.
+    Range {
+        /// The lower bound of the range.
+        start: Bound<&'static str>,
+        /// The upper bound of the range.
+        end: Bound<&'static str>,
+    },
+    /// The value must be at most this many characters long.
+    MaxLength(usize),
+    /// The value must match the regular expression.
+    Regex(&'static str),
+}
+
+impl fmt::Display for Constraint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Constraint::Range {
+                start,
+                end,
+            } => match (start, end) {
+                (Bound::Included(start), Bound::Included(end)) => {
+                    write!(f, "must be between {} and {}", start, end)
+                },
+                (Bound::Included(start), Bound::Excluded(end)) => {
+                    write!(f, "must be at least {} and less than {}", start, end)
+                },
+                (Bound::Included(start), Bound::Unbounded) => {
+                    write!(f, "must be at least {}", start)
+                },
+                (_, Bound::Included(end)) => write!(f, "must be at most {}", end),
+                (_, Bound::Excluded(end)) => write!(f, "must be less than {}", end),
+                (_, Bound::Unbounded) => f.write_str("must be within range"),
+            },
+            Constraint::MaxLength(len) => write!(f, "must be at most {} characters long", len),
+            Constraint::Regex(regex) => write!(f, "must match the pattern `{}`", regex),
+        }
+    }
+}
+
+/// Returns an error if a parsed argument violates a constraint.
+///
+/// The argument was parsed out of the segments since the `source` at the `start`
+/// offset. This is used by the [`command`] macro.
+///
+/// [`command`]: crate::prelude::command
+pub fn check_constraint(
+    valid: bool,
+    constraint: &'static Constraint,
+    segments: &ArgumentSegments<'_>,
+    source: &str,
+    start: usize,
+) -> Result<(), ArgumentError<Infallible>> {
+    if valid {
+        return Ok(());
+    }
+
+    let (input, span) = consumed_input(segments, source, start);
+
+    Err(ArgumentError::new(ArgumentErrorKind::Constraint(constraint), input, span))
+}
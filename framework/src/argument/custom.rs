@@ -0,0 +1,70 @@
+//! Parsing of arguments with custom parsers.
+//!
+//! A [`CustomParser`] parses segments with a function given by the caller,
+//! instead of an implementation of [`Argument`]. It is given to the
+//! [argument parsers][parsers] of the parent module for command parameters
+//! marked with `#[parse_with(...)]`; refer to the [parent module][parent] for
+//! an example of a command.
+//!
+//! A custom parser receives the text of the segment, without its quotation
+//! marks, or the remainder of the segments for [`rest_argument`].
+//!
+//! # Examples
+//!
+//! ```rust
+//! use serenity_framework::argument::custom::CustomParser;
+//! use serenity_framework::argument::parser::complete;
+//! use serenity_framework::argument::{optional_argument, variadic_arguments};
+//! use serenity_framework::utils::ArgumentSegments;
+//!
+//! fn hex(s: &str) -> Result<u32, std::num::ParseIntError> {
+//!     u32::from_str_radix(s.trim_start_matches('#'), 16)
+//! }
+//!
+//! let mut segments = ArgumentSegments::new("#ff0000 ff 80", " ");
+//!
+//! let first = complete(optional_argument(&mut segments, CustomParser::new(hex)));
+//! assert_eq!(first.unwrap(), Some(0xff0000));
+//!
+//! let rest = complete(variadic_arguments(&mut segments, CustomParser::new(hex)));
+//! assert_eq!(rest.unwrap(), vec![0xff, 0x80]);
+//!
+//! let none = complete(optional_argument(&mut segments, CustomParser::new(hex)));
+//! assert_eq!(none.unwrap(), None);
+//! ```
+//!
+//! [parsers]: super::required_argument
+//! [parent]: super
+//! [`Argument`]: super::Argument
+//! [`rest_argument`]: super::rest_argument
+
+use serenity::async_trait;
+
+use super::Parser;
+use crate::utils::ArgumentSegments;
+
+/// Parses the next segment with a function.
+#[derive(Debug, Clone, Copy)]
+pub struct CustomParser<F>(F);
+
+impl<F> CustomParser<F> {
+    /// Creates a parser from a function.
+    pub fn new(parser: F) -> Self {
+        Self(parser)
+    }
+}
+
+#[async_trait]
+impl<T, Err, F> Parser for CustomParser<F>
+where
+    F: Fn(&str) -> Result<T, Err> + Send,
+{
+    type Output = T;
+    type Error = Err;
+
+    async fn parse(&mut self, segments: &mut ArgumentSegments<'_>) -> Result<T, Err> {
+        let segment = segments.next().unwrap_or_default();
+
+        (self.0)(&segment)
+    }
+}
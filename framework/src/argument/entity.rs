@@ -0,0 +1,307 @@
+//! Parsing of Discord entities.
+//!
+//! Entities are parsed out of a mention, a raw id, or a name. Names are
+//! resolved through the cache for the guild the message was sent in.
+//! Entities that are missing in the cache are requested from Discord.
+//!
+//! The following types are supported:
+//!
+//! - [`UserId`] and [`Member`]: `<@id>`, `<@!id>`, `id`, `name#discriminator`, `name` or `nickname`.
+//! - [`ChannelId`] and [`GuildChannel`]: `<#id>`, `id`, `name` or `#name`.
+//! - [`RoleId`] and [`Role`]: `<@&id>`, `id`, `name` or `@name`.
+//!
+//! Names are compared case-insensitively. Raw ids must be at least 17 digits
+//! long, like every snowflake, so that ordinary numbers are not mistaken for ids.
+//!
+//! [`UserId`]: serenity::model::id::UserId
+//! [`Member`]: serenity::model::guild::Member
+//! [`ChannelId`]: serenity::model::id::ChannelId
+//! [`GuildChannel`]: serenity::model::channel::GuildChannel
+//! [`RoleId`]: serenity::model::id::RoleId
+//! [`Role`]: serenity::model::guild::Role
+
+use std::error::Error as StdError;
+use std::fmt;
+
+use serenity::async_trait;
+use serenity::model::channel::{Channel, GuildChannel, Message};
+use serenity::model::guild::{Member, Role};
+use serenity::model::id::{ChannelId, GuildId, RoleId, UserId};
+use serenity::utils::{parse_channel, parse_role, parse_username};
+use serenity::Error as SerenityError;
+
+use super::Argument;
+use crate::context::Context;
+use crate::utils::ArgumentSegments;
+
+/// Error that might occur when parsing a Discord entity.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum EntityError {
+    /// The argument does not refer to any entity.
+    NotFound,
+    /// The entity only exists in guilds, but the message was not sent in one.
+    NotInGuild,
+    /// Requesting the entity from Discord failed.
+    ///
+    /// Entities that Discord reports as missing result in [`NotFound`] instead.
+    ///
+    /// [`NotFound`]: Self::NotFound
+    Http(SerenityError),
+}
+
+impl fmt::Display for EntityError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EntityError::NotFound => f.write_str("not found"),
+            EntityError::NotInGuild => f.write_str("only available in guilds"),
+            EntityError::Http(err) => fmt::Display::fmt(err, f),
+        }
+    }
+}
+
+impl StdError for EntityError {
+    fn source(&self) -> Option<&(dyn StdError + 'static)> {
+        match self {
+            EntityError::Http(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+fn guild_id(msg: &Message) -> Result<GuildId, EntityError> {
+    msg.guild_id.ok_or(EntityError::NotInGuild)
+}
+
+/// Converts an error of a request for an entity, treating missing entities as not found.
+fn http_error(err: SerenityError) -> EntityError {
+    match &err {
+        SerenityError::Http(http) if http.status_code().map(|c| c.as_u16()) == Some(404) => {
+            EntityError::NotFound
+        },
+        _ => EntityError::Http(err),
+    }
+}
+
+/// Parses a raw id, which must have the shape of a snowflake.
+fn snowflake(segment: &str) -> Option<u64> {
+    if segment.len() < 17 || !segment.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+
+    segment.parse().ok()
+}
+
+fn user_id(segment: &str) -> Option<UserId> {
+    parse_username(segment).or_else(|| snowflake(segment)).map(UserId)
+}
+
+fn channel_id(segment: &str) -> Option<ChannelId> {
+    parse_channel(segment).or_else(|| snowflake(segment)).map(ChannelId)
+}
+
+fn role_id(segment: &str) -> Option<RoleId> {
+    parse_role(segment).or_else(|| snowflake(segment)).map(RoleId)
+}
+
+/// Finds a member of a guild by its tag, name or nickname, in that order.
+async fn member_named<D, E>(ctx: &Context<D, E>, guild_id: GuildId, name: &str) -> Option<Member> {
+    ctx.serenity_ctx
+        .cache
+        .guild_field(guild_id, |guild| {
+            let members = || guild.members.values();
+
+            members()
+                .find(|m| m.user.tag().eq_ignore_ascii_case(name))
+                .or_else(|| members().find(|m| m.user.name.eq_ignore_ascii_case(name)))
+                .or_else(|| {
+                    members()
+                        .find(|m| m.nick.as_ref().is_some_and(|n| n.eq_ignore_ascii_case(name)))
+                })
+                .cloned()
+        })
+        .await
+        .flatten()
+}
+
+/// Finds a channel of a guild by its name.
+async fn channel_named<D, E>(
+    ctx: &Context<D, E>,
+    guild_id: GuildId,
+    name: &str,
+) -> Option<GuildChannel> {
+    let name = name.strip_prefix('#').unwrap_or(name);
+
+    ctx.serenity_ctx
+        .cache
+        .guild_field(guild_id, |guild| {
+            guild.channels.values().find(|c| c.name.eq_ignore_ascii_case(name)).cloned()
+        })
+        .await
+        .flatten()
+}
+
+/// Finds a role of a guild by its name.
+async fn role_named<D, E>(ctx: &Context<D, E>, guild_id: GuildId, name: &str) -> Option<Role> {
+    let name = name.strip_prefix('@').unwrap_or(name);
+
+    ctx.serenity_ctx
+        .cache
+        .guild_field(guild_id, |guild| {
+            guild.roles.values().find(|r| r.name.eq_ignore_ascii_case(name)).cloned()
+        })
+        .await
+        .flatten()
+}
+
+#[async_trait]
+impl<D, E> Argument<D, E> for UserId
+where
+    D: Send + Sync,
+    E: Send + Sync,
+{
+    type Error = EntityError;
+
+    async fn parse(
+        ctx: &Context<D, E>,
+        msg: &Message,
+        segments: &mut ArgumentSegments<'_>,
+    ) -> Result<Self, Self::Error> {
+        let segment = segments.next().unwrap_or_default();
+
+        if let Some(id) = user_id(&segment) {
+            return Ok(id);
+        }
+
+        let member = member_named(ctx, guild_id(msg)?, &segment).await;
+        member.map(|m| m.user.id).ok_or(EntityError::NotFound)
+    }
+}
+
+#[async_trait]
+impl<D, E> Argument<D, E> for Member
+where
+    D: Send + Sync,
+    E: Send + Sync,
+{
+    type Error = EntityError;
+
+    async fn parse(
+        ctx: &Context<D, E>,
+        msg: &Message,
+        segments: &mut ArgumentSegments<'_>,
+    ) -> Result<Self, Self::Error> {
+        let guild_id = guild_id(msg)?;
+        let segment = segments.next().unwrap_or_default();
+
+        if let Some(id) = user_id(&segment) {
+            return guild_id.member(&ctx.serenity_ctx, id).await.map_err(http_error);
+        }
+
+        member_named(ctx, guild_id, &segment).await.ok_or(EntityError::NotFound)
+    }
+}
+
+#[async_trait]
+impl<D, E> Argument<D, E> for ChannelId
+where
+    D: Send + Sync,
+    E: Send + Sync,
+{
+    type Error = EntityError;
+
+    async fn parse(
+        ctx: &Context<D, E>,
+        msg: &Message,
+        segments: &mut ArgumentSegments<'_>,
+    ) -> Result<Self, Self::Error> {
+        let segment = segments.next().unwrap_or_default();
+
+        if let Some(id) = channel_id(&segment) {
+            return Ok(id);
+        }
+
+        let channel = channel_named(ctx, guild_id(msg)?, &segment).await;
+        channel.map(|c| c.id).ok_or(EntityError::NotFound)
+    }
+}
+
+#[async_trait]
+impl<D, E> Argument<D, E> for GuildChannel
+where
+    D: Send + Sync,
+    E: Send + Sync,
+{
+    type Error = EntityError;
+
+    async fn parse(
+        ctx: &Context<D, E>,
+        msg: &Message,
+        segments: &mut ArgumentSegments<'_>,
+    ) -> Result<Self, Self::Error> {
+        let guild_id = guild_id(msg)?;
+        let segment = segments.next().unwrap_or_default();
+
+        if let Some(id) = channel_id(&segment) {
+            return match id.to_channel(&ctx.serenity_ctx).await.map_err(http_error)? {
+                Channel::Guild(channel) if channel.guild_id == guild_id => Ok(channel),
+                _ => Err(EntityError::NotFound),
+            };
+        }
+
+        channel_named(ctx, guild_id, &segment).await.ok_or(EntityError::NotFound)
+    }
+}
+
+#[async_trait]
+impl<D, E> Argument<D, E> for RoleId
+where
+    D: Send + Sync,
+    E: Send + Sync,
+{
+    type Error = EntityError;
+
+    async fn parse(
+        ctx: &Context<D, E>,
+        msg: &Message,
+        segments: &mut ArgumentSegments<'_>,
+    ) -> Result<Self, Self::Error> {
+        let segment = segments.next().unwrap_or_default();
+
+        if let Some(id) = role_id(&segment) {
+            return Ok(id);
+        }
+
+        let role = role_named(ctx, guild_id(msg)?, &segment).await;
+        role.map(|r| r.id).ok_or(EntityError::NotFound)
+    }
+}
+
+#[async_trait]
+impl<D, E> Argument<D, E> for Role
+where
+    D: Send + Sync,
+    E: Send + Sync,
+{
+    type Error = EntityError;
+
+    async fn parse(
+        ctx: &Context<D, E>,
+        msg: &Message,
+        segments: &mut ArgumentSegments<'_>,
+    ) -> Result<Self, Self::Error> {
+        let guild_id = guild_id(msg)?;
+        let segment = segments.next().unwrap_or_default();
+
+        if let Some(id) = role_id(&segment) {
+            if let Some(role) = ctx.serenity_ctx.cache.role(guild_id, id).await {
+                return Ok(role);
+            }
+
+            let mut roles = guild_id.roles(&ctx.serenity_ctx).await.map_err(http_error)?;
+            return roles.remove(&id).ok_or(EntityError::NotFound);
+        }
+
+        role_named(ctx, guild_id, &segment).await.ok_or(EntityError::NotFound)
+    }
+}
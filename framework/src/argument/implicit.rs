@@ -0,0 +1,165 @@
+//! Arguments taken from the message invoking a command, rather than from its content.
+//!
+//! The [`command`] macro fills parameters marked with `#[implicit]` from the message.
+//! Their type must implement [`Implicit`], which the framework implements for:
+//!
+//! - [`Attachment`]: the first file attached to the message. `Vec<Attachment>` takes
+//!   every attached file.
+//! - [`ReferencedMessage`]: the message replied to.
+//!
+//! Implicit parameters are required, unless wrapped in an `Option`. They do not take
+//! any arguments, and may thus be placed anywhere among the parameters of a command.
+//! They are not listed in the [parameters] of the command.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use serenity::model::channel::{Attachment, Message};
+//! use serenity_framework::argument::ReferencedMessage;
+//! use serenity_framework::prelude::*;
+//!
+//! #[command]
+//! async fn quote(
+//!     _ctx: FrameworkContext,
+//!     _msg: &Message,
+//!     #[implicit] reply: ReferencedMessage,
+//!     #[implicit] image: Option<Attachment>,
+//! ) -> CommandResult {
+//!     assert_eq!(reply.content, "hello");
+//!     assert!(image.map_or(true, |image| image.filename == "cat.png"));
+//!     Ok(())
+//! }
+//!
+//! # async fn dispatch<D: Send + Sync, E: std::fmt::Display>(
+//! #     framework: &Framework<D, E>,
+//! #     content: &str,
+//! #     reply: Option<&str>,
+//! #     files: &[&str],
+//! # ) -> Result<(), String> {
+//! #     use std::sync::Arc;
+//! #     use serenity::prelude::{RwLock, TypeMap};
+//! #     let ctx = serenity::client::Context {
+//! #         data: Arc::new(RwLock::new(TypeMap::new())),
+//! #         shard: serenity::client::bridge::gateway::ShardMessenger::new(
+//! #             serenity::futures::channel::mpsc::unbounded().0,
+//! #         ),
+//! #         shard_id: 0,
+//! #         http: Arc::new(serenity::http::Http::new_with_token("")),
+//! #         cache: Arc::new(serenity::cache::Cache::new()),
+//! #     };
+//! #     let message = |content: &str| serde_json::json!({
+//! #         "id": "1", "channel_id": "1", "type": 0, "content": content,
+//! #         "author": { "id": "1", "username": "user", "discriminator": "0001", "avatar": null },
+//! #         "attachments": [], "embeds": [], "mentions": [], "mention_roles": [],
+//! #         "mention_everyone": false, "pinned": false, "tts": false,
+//! #         "timestamp": "2021-01-01T00:00:00Z",
+//! #     });
+//! #     let mut msg = message(content);
+//! #     if let Some(reply) = reply {
+//! #         msg["referenced_message"] = message(reply);
+//! #     }
+//! #     msg["attachments"] = files.iter().map(|file| serde_json::json!({
+//! #         "id": "1", "filename": file, "size": 0, "url": "", "proxy_url": "",
+//! #         "height": null, "width": null,
+//! #     })).collect();
+//! #     let msg = serde_json::from_value(msg).unwrap();
+//! #     framework.dispatch(&ctx, &msg).await.map_err(|e| e.to_string())
+//! # }
+//! # #[tokio::main(flavor = "current_thread")]
+//! # async fn main() {
+//! let mut conf: Configuration = Configuration::new();
+//! conf.prefix("!").command(quote);
+//! let framework: Framework = Framework::new(conf);
+//!
+//! // `dispatch` invokes the framework with a message, replying to a message and
+//! // attaching files if given, and returns its error as text.
+//! assert_eq!(dispatch(&framework, "!quote", Some("hello"), &[]).await, Ok(()));
+//! assert_eq!(dispatch(&framework, "!quote", Some("hello"), &["cat.png"]).await, Ok(()));
+//!
+//! let err = dispatch(&framework, "!quote", None, &["cat.png"]).await.unwrap_err();
+//! assert_eq!(err, "`reply`: missing required argument");
+//! # }
+//! ```
+//!
+//! [`command`]: crate::prelude::command
+//! [`Attachment`]: serenity::model::channel::Attachment
+//! [parameters]: crate::command::Command::parameters
+
+use std::convert::Infallible;
+use std::ops::Deref;
+
+use serenity::model::channel::{Attachment, Message};
+
+use super::{ArgumentError, ArgumentErrorKind};
+use crate::context::Context;
+
+/// A type whose value is taken from the message invoking a command.
+pub trait Implicit: Sized {
+    /// Takes the value out of the message, if present.
+    fn from_message(msg: &Message) -> Option<Self>;
+}
+
+impl Implicit for Attachment {
+    fn from_message(msg: &Message) -> Option<Self> {
+        msg.attachments.first().cloned()
+    }
+}
+
+impl Implicit for Vec<Attachment> {
+    fn from_message(msg: &Message) -> Option<Self> {
+        Some(msg.attachments.clone())
+    }
+}
+
+/// The message replied to by the message invoking a command.
+#[derive(Debug, Clone)]
+pub struct ReferencedMessage(pub Message);
+
+impl ReferencedMessage {
+    /// Returns the message replied to.
+    pub fn into_inner(self) -> Message {
+        self.0
+    }
+}
+
+impl Deref for ReferencedMessage {
+    type Target = Message;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl Implicit for ReferencedMessage {
+    fn from_message(msg: &Message) -> Option<Self> {
+        msg.referenced_message.as_deref().cloned().map(ReferencedMessage)
+    }
+}
+
+/// Takes an argument out of the message invoking the command.
+///
+/// # Errors
+///
+/// If the message does not contain the argument, [`ArgumentErrorKind::Missing`]
+/// is returned, pointing at the end of the arguments of the command.
+pub fn required_implicit<D, E, T>(
+    ctx: &Context<D, E>,
+    msg: &Message,
+) -> Result<T, ArgumentError<Infallible>>
+where
+    T: Implicit,
+{
+    let end = ctx.args.len();
+
+    T::from_message(msg).ok_or_else(|| ArgumentError::new(ArgumentErrorKind::Missing, "", end..end))
+}
+
+/// Tries to take an argument out of the message invoking the command.
+///
+/// If the message does not contain the argument, `None` is returned.
+pub fn optional_implicit<T>(msg: &Message) -> Option<T>
+where
+    T: Implicit,
+{
+    T::from_message(msg)
+}
@@ -0,0 +1,1217 @@
+//! Utilities for parsing command arguments.
+//!
+//! Arguments are parsed by types implementing the [`Argument`] trait. It is
+//! implemented for common types implementing [`FromStr`]. Other types implementing
+//! [`FromStr`] can be parsed through the [`FromStrArgument`] adapter.
+//!
+//! Discord entities, such as users, channels and roles, are parsed with the help
+//! of the cache. Refer to the [`entity`] module.
+//!
+//! Durations, timestamps, colours and booleans are parsed in formats common in
+//! chat. Refer to the [`common`] module. Code is parsed verbatim out of code
+//! blocks; refer to the [`code`] module.
+//!
+//! Optional arguments that fail to be parsed are skipped, leaving their segments
+//! to the next parameters. Parameters of the [`Greedy`] type parse as many
+//! arguments as possible in the same manner.
+//!
+//! Besides positional arguments, commands can accept flags and options by name.
+//! Refer to the [`named`] module. Attachments and the message replied to are
+//! taken from the message itself; refer to the [`implicit`] module.
+//!
+//! Arguments chosen out of a fixed set are declared as enums deriving [`Choice`].
+//! Refer to the [`choice`] module.
+//!
+//! Sets of arguments shared across commands are declared as structs deriving
+//! [`Arguments`]. Types that do not need any context to be parsed implement
+//! [`FromSegments`], and are thus arguments too. Sets of such arguments may
+//! derive [`FromSegments`] as well, allowing them to be parsed in unit tests.
+//!
+//! Parameters marked with `#[default = ...]` are optional, taking the value of the
+//! expression if their argument is absent or fails to be parsed. Parameters marked
+//! with `#[parse_with(...)]` are parsed by a function instead of their type; refer
+//! to the [`custom`] module.
+//!
+//! The same rules apply to parsing any kind of argument; refer to the [`parser`] module.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use serenity::model::channel::Message;
+//! use serenity_framework::prelude::*;
+//!
+//! fn hex(s: &str) -> Result<u32, std::num::ParseIntError> {
+//!     u32::from_str_radix(s.trim_start_matches('#'), 16)
+//! }
+//!
+//! #[command]
+//! async fn paint(
+//!     _ctx: FrameworkContext,
+//!     _msg: &Message,
+//!     #[parse_with(hex)] colour: u32,
+//!     #[default = 1] coats: u8,
+//! ) -> CommandResult {
+//!     assert_eq!(colour, 0xff0000);
+//!     assert_eq!(coats, 1);
+//!     Ok(())
+//! }
+//!
+//! # async fn dispatch<D: Send + Sync, E: std::fmt::Display>(
+//! #     framework: &Framework<D, E>,
+//! #     content: &str,
+//! # ) -> Result<(), String> {
+//! #     use std::sync::Arc;
+//! #     use serenity::prelude::{RwLock, TypeMap};
+//! #     let ctx = serenity::client::Context {
+//! #         data: Arc::new(RwLock::new(TypeMap::new())),
+//! #         shard: serenity::client::bridge::gateway::ShardMessenger::new(
+//! #             serenity::futures::channel::mpsc::unbounded().0,
+//! #         ),
+//! #         shard_id: 0,
+//! #         http: Arc::new(serenity::http::Http::new_with_token("")),
+//! #         cache: Arc::new(serenity::cache::Cache::new()),
+//! #     };
+//! #     let msg = serde_json::from_value(serde_json::json!({
+//! #         "id": "1", "channel_id": "1", "type": 0, "content": content,
+//! #         "author": { "id": "1", "username": "user", "discriminator": "0001", "avatar": null },
+//! #         "attachments": [], "embeds": [], "mentions": [], "mention_roles": [],
+//! #         "mention_everyone": false, "pinned": false, "tts": false,
+//! #         "timestamp": "2021-01-01T00:00:00Z",
+//! #     }));
+//! #     framework.dispatch(&ctx, &msg.unwrap()).await.map_err(|e| e.to_string())
+//! # }
+//! # #[tokio::main(flavor = "current_thread")]
+//! # async fn main() {
+//! let mut conf: Configuration = Configuration::new();
+//! conf.prefix("!").command(paint);
+//! let framework: Framework = Framework::new(conf);
+//!
+//! // `dispatch` invokes the framework with a message, returning its error as text.
+//! assert_eq!(dispatch(&framework, "!paint #ff0000").await, Ok(()));
+//! assert_eq!(dispatch(&framework, "!paint ff0000 many").await, Ok(()));
+//!
+//! let err = dispatch(&framework, "!paint red").await.unwrap_err();
+//! assert_eq!(err, "argument 1 (`colour`): `red`: invalid digit found in string");
+//! # }
+//! ```
+//! The amount of arguments of a [`Vec`] or [`Greedy`] parameter is bounded with
+//! `#[count(min = ..., max = ...)]`:
+//!
+//! ```rust
+//! use serenity::model::channel::Message;
+//! use serenity_framework::prelude::*;
+//!
+//! #[command]
+//! async fn tag(
+//!     _ctx: FrameworkContext,
+//!     _msg: &Message,
+//!     #[count(min = 1, max = 3)] tags: Vec<String>,
+//! ) -> CommandResult {
+//!     assert!((1..=3).contains(&tags.len()));
+//!     Ok(())
+//! }
+//!
+//! # async fn dispatch<D: Send + Sync, E: std::fmt::Display>(
+//! #     framework: &Framework<D, E>,
+//! #     content: &str,
+//! # ) -> Result<(), String> {
+//! #     use std::sync::Arc;
+//! #     use serenity::prelude::{RwLock, TypeMap};
+//! #     let ctx = serenity::client::Context {
+//! #         data: Arc::new(RwLock::new(TypeMap::new())),
+//! #         shard: serenity::client::bridge::gateway::ShardMessenger::new(
+//! #             serenity::futures::channel::mpsc::unbounded().0,
+//! #         ),
+//! #         shard_id: 0,
+//! #         http: Arc::new(serenity::http::Http::new_with_token("")),
+//! #         cache: Arc::new(serenity::cache::Cache::new()),
+//! #     };
+//! #     let msg = serde_json::from_value(serde_json::json!({
+//! #         "id": "1", "channel_id": "1", "type": 0, "content": content,
+//! #         "author": { "id": "1", "username": "user", "discriminator": "0001", "avatar": null },
+//! #         "attachments": [], "embeds": [], "mentions": [], "mention_roles": [],
+//! #         "mention_everyone": false, "pinned": false, "tts": false,
+//! #         "timestamp": "2021-01-01T00:00:00Z",
+//! #     }));
+//! #     framework.dispatch(&ctx, &msg.unwrap()).await.map_err(|e| e.to_string())
+//! # }
+//! # #[tokio::main(flavor = "current_thread")]
+//! # async fn main() {
+//! let mut conf: Configuration = Configuration::new();
+//! conf.prefix("!").command(tag);
+//! let framework: Framework = Framework::new(conf);
+//!
+//! assert_eq!(dispatch(&framework, "!tag rust discord").await, Ok(()));
+//!
+//! let err = dispatch(&framework, "!tag").await.unwrap_err();
+//! assert_eq!(err, "argument 1 (`tags`): missing required argument");
+//!
+//! let err = dispatch(&framework, "!tag a b c d").await.unwrap_err();
+//! assert_eq!(err, "argument 1 (`tags`): `a b c d`: too many arguments");
+//! # }
+//! ```
+
+use std::convert::Infallible;
+use std::error::Error as StdError;
+use std::fmt;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr, SocketAddrV4, SocketAddrV6};
+use std::num::{
+    NonZeroI128,
+    NonZeroI16,
+    NonZeroI32,
+    NonZeroI64,
+    NonZeroI8,
+    NonZeroIsize,
+    NonZeroU128,
+    NonZeroU16,
+    NonZeroU32,
+    NonZeroU64,
+    NonZeroU8,
+    NonZeroUsize,
+};
+use std::ops::{Deref, DerefMut, Range};
+use std::path::PathBuf;
+use std::str::FromStr;
+
+use serenity::async_trait;
+use serenity::futures::future::BoxFuture;
+use serenity::model::channel::Message;
+
+use crate::context::Context;
+use crate::utils::{ArgumentSegments, UnbalancedQuote};
+use crate::{DefaultData, DefaultError};
+
+pub mod choice;
+pub mod code;
+pub mod common;
+pub mod constraint;
+pub mod custom;
+pub mod entity;
+pub mod implicit;
+pub mod named;
+pub mod parser;
+
+pub use choice::{Choice, InvalidChoice};
+pub use code::{CodeBlock, InvalidCodeBlock};
+#[cfg(feature = "macros")]
+pub use command_attr::{Arguments, FromSegments};
+pub use common::{DurationError, InvalidBool, InvalidColour, InvalidTimestamp};
+pub use constraint::Constraint;
+pub use entity::EntityError;
+pub use implicit::{Implicit, ReferencedMessage};
+pub use named::{MissingValue, NamedArguments};
+pub use parser::{ArgumentParser, Parser, SegmentsParser};
+
+/// Error that might have occured when trying to parse an argument.
+///
+/// Besides the [kind of error][kind], it describes the parameter of the command
+/// and the input that caused the error.
+///
+/// [kind]: ArgumentErrorKind
+#[derive(Debug)]
+#[non_exhaustive]
+pub struct ArgumentError<E> {
+    /// The kind of error.
+    pub kind: ArgumentErrorKind<E>,
+    /// The parameter of the command that failed to be parsed.
+    ///
+    /// This is filled by the [`command`] macro.
+    ///
+    /// It is boxed to keep the error small.
+    ///
+    /// [`command`]: crate::prelude::command
+    pub parameter: Option<Box<Parameter>>,
+    /// The raw input that failed to be parsed.
+    ///
+    /// This is empty if the input is missing.
+    pub input: String,
+    /// The byte span of the input in the [arguments of the command][args].
+    ///
+    /// [args]: crate::context::Context::args
+    pub span: Range<usize>,
+}
+
+impl<E> ArgumentError<E> {
+    /// Creates a new argument error caused by the input at a byte span.
+    pub fn new<I>(kind: ArgumentErrorKind<E>, input: I, span: Range<usize>) -> Self
+    where
+        I: Into<String>,
+    {
+        Self {
+            kind,
+            parameter: None,
+            input: input.into(),
+            span,
+        }
+    }
+
+    /// Assigns the parameter that failed to be parsed.
+    pub fn with_parameter(mut self, parameter: Parameter) -> Self {
+        self.parameter = Some(Box::new(parameter));
+        self
+    }
+
+    /// Converts the error of the argument with a function.
+    pub fn map<F, T>(self, f: F) -> ArgumentError<T>
+    where
+        F: FnOnce(E) -> T,
+    {
+        let kind = match self.kind {
+            ArgumentErrorKind::Missing => ArgumentErrorKind::Missing,
+            ArgumentErrorKind::Argument(err) => ArgumentErrorKind::Argument(f(err)),
+            ArgumentErrorKind::UnbalancedQuote(err) => ArgumentErrorKind::UnbalancedQuote(err),
+            ArgumentErrorKind::MissingValue(err) => ArgumentErrorKind::MissingValue(err),
+            ArgumentErrorKind::Constraint(constraint) => ArgumentErrorKind::Constraint(constraint),
+            ArgumentErrorKind::TooMany => ArgumentErrorKind::TooMany,
+        };
+
+        ArgumentError {
+            kind,
+            parameter: self.parameter,
+            input: self.input,
+            span: self.span,
+        }
+    }
+
+    /// Boxes the error of the argument, to unify errors of different arguments.
+    pub fn boxed(self) -> ArgumentError<BoxedError>
+    where
+        E: StdError + Send + Sync + 'static,
+    {
+        self.map(|err| BoxedError(Box::new(err)))
+    }
+
+    /// Converts the span of the input with a function mapping byte indices.
+    pub fn map_span<F>(mut self, f: F) -> Self
+    where
+        F: Fn(usize) -> usize,
+    {
+        let start = f(self.span.start);
+        let end = if self.span.is_empty() { start } else { f(self.span.end - 1) + 1 };
+
+        self.span = start..end;
+        self
+    }
+
+    /// Returns a line of carets pointing at the input, to be displayed under
+    /// the arguments of the command.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity_framework::argument::{ArgumentError, ArgumentErrorKind};
+    ///
+    /// let args = "5 abc";
+    /// let error = ArgumentError::new(ArgumentErrorKind::Argument(()), "abc", 2..5);
+    ///
+    /// assert_eq!(error.caret(args), "  ^^^");
+    /// ```
+    pub fn caret(&self, args: &str) -> String {
+        let offset = args.get(..self.span.start).map_or(0, |s| s.chars().count());
+        let width = args.get(self.span.clone()).map_or(0, |s| s.chars().count());
+
+        format!("{}{}", " ".repeat(offset), "^".repeat(width.max(1)))
+    }
+}
+
+impl<E: fmt::Display> fmt::Display for ArgumentError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(parameter) = &self.parameter {
+            match parameter.index {
+                Some(index) => write!(f, "argument {} (`{}`): ", index + 1, parameter.name)?,
+                None => write!(f, "`{}`: ", parameter.name)?,
+            }
+        }
+
+        match &self.kind {
+            ArgumentErrorKind::Argument(_)
+            | ArgumentErrorKind::Constraint(_)
+            | ArgumentErrorKind::TooMany
+                if !self.input.is_empty() =>
+            {
+                write!(f, "`{}`: {}", self.input, self.kind)
+            },
+            kind => fmt::Display::fmt(kind, f),
+        }
+    }
+}
+
+impl<E: StdError + 'static> StdError for ArgumentError<E> {
+    fn source(&self) -> Option<&(dyn StdError + 'static)> {
+        self.kind.source()
+    }
+}
+
+/// The kind of an [`ArgumentError`].
+#[derive(Debug)]
+pub enum ArgumentErrorKind<E> {
+    /// Required argument is missing.
+    ///
+    /// This is only returned by the [`required_argument`], [`required_option`],
+    /// [`required_implicit`] and [`check_count`] functions.
+    ///
+    /// [`required_implicit`]: implicit::required_implicit
+    Missing,
+    /// Parsing the argument failed.
+    ///
+    /// Contains the error from [`Argument::Error`].
+    Argument(E),
+    /// The argument is missing its closing quotation mark.
+    UnbalancedQuote(UnbalancedQuote),
+    /// An option is given without a value.
+    MissingValue(MissingValue),
+    /// The argument violates a constraint of the parameter.
+    ///
+    /// Refer to the [`constraint`] module.
+    Constraint(&'static Constraint),
+    /// Arguments are left over after the last parameter, or given in excess to
+    /// a parameter.
+    ///
+    /// This is only returned by the [`check_extraneous`] and [`check_count`] functions.
+    TooMany,
+}
+
+impl<E: fmt::Display> fmt::Display for ArgumentErrorKind<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ArgumentErrorKind::Missing => f.write_str("missing required argument"),
+            ArgumentErrorKind::Argument(err) => fmt::Display::fmt(err, f),
+            ArgumentErrorKind::UnbalancedQuote(err) => fmt::Display::fmt(err, f),
+            ArgumentErrorKind::MissingValue(err) => fmt::Display::fmt(err, f),
+            ArgumentErrorKind::Constraint(constraint) => fmt::Display::fmt(constraint, f),
+            ArgumentErrorKind::TooMany => f.write_str("too many arguments"),
+        }
+    }
+}
+
+impl<E: StdError + 'static> StdError for ArgumentErrorKind<E> {
+    fn source(&self) -> Option<&(dyn StdError + 'static)> {
+        match self {
+            ArgumentErrorKind::Argument(err) => Some(err),
+            ArgumentErrorKind::UnbalancedQuote(err) => Some(err),
+            ArgumentErrorKind::MissingValue(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+/// An error of any type, used to unify the errors of different arguments.
+#[derive(Debug)]
+pub struct BoxedError(pub Box<dyn StdError + Send + Sync>);
+
+impl fmt::Display for BoxedError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.0, f)
+    }
+}
+
+impl StdError for BoxedError {
+    fn source(&self) -> Option<&(dyn StdError + 'static)> {
+        self.0.source()
+    }
+}
+
+/// A parameter of a command.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[non_exhaustive]
+pub struct Parameter {
+    /// The name of the parameter.
+    pub name: &'static str,
+    /// The type of the parameter, as written in the command.
+    pub ty: &'static str,
+    /// The index of the parameter among the positional arguments of the command,
+    /// starting at 0.
+    ///
+    /// This is `None` for parameters that are not positional, such as [flags and options].
+    ///
+    /// [flags and options]: NamedArguments
+    pub index: Option<usize>,
+    /// The constraints on the value of the parameter.
+    pub constraints: &'static [Constraint],
+    /// The names of the values the parameter is chosen out of, if its type is a [`Choice`].
+    ///
+    /// This is empty for other types. Refer to [`Argument::choices`].
+    pub choices: &'static [&'static str],
+}
+
+impl Parameter {
+    /// Creates a new positional parameter.
+    pub fn new(name: &'static str, ty: &'static str, index: usize) -> Self {
+        Self {
+            name,
+            ty,
+            index: Some(index),
+            constraints: &[],
+            choices: &[],
+        }
+    }
+
+    /// Creates a new parameter that is not positional.
+    pub fn named(name: &'static str, ty: &'static str) -> Self {
+        Self {
+            name,
+            ty,
+            index: None,
+            constraints: &[],
+            choices: &[],
+        }
+    }
+
+    /// Assigns the constraints on the value of the parameter.
+    pub fn with_constraints(mut self, constraints: &'static [Constraint]) -> Self {
+        self.constraints = constraints;
+        self
+    }
+
+    /// Assigns the names of the values the parameter is chosen out of.
+    pub fn with_choices(mut self, choices: &'static [&'static str]) -> Self {
+        self.choices = choices;
+        self
+    }
+}
+
+/// A type that can be parsed from the arguments of a command.
+///
+/// Parsing is asynchronous and has access to the [`Context`] and the message
+/// of the command, allowing to consult the cache, the HTTP client, or the user data.
+#[async_trait]
+pub trait Argument<D = DefaultData, E = DefaultError>: Sized {
+    /// The error that might occur when parsing the argument.
+    type Error;
+
+    /// Parses the argument out of the segments.
+    ///
+    /// Implementors take as many segments as they need, usually one.
+    ///
+    /// The segments are only empty when parsing a [rest argument][rest] out of nothing.
+    ///
+    /// [rest]: rest_argument
+    async fn parse(
+        ctx: &Context<D, E>,
+        msg: &Message,
+        segments: &mut ArgumentSegments<'_>,
+    ) -> Result<Self, Self::Error>;
+
+    /// Returns the names of the values the argument is chosen out of, if it is chosen
+    /// out of a fixed set, such as a [`Choice`]. They are listed in the help of commands.
+    ///
+    /// By default, this is empty.
+    fn choices() -> &'static [&'static str] {
+        &[]
+    }
+}
+
+/// A type that can be parsed from the arguments of a command without any context.
+///
+/// Unlike [`Argument`], parsing is synchronous and only has access to the segments.
+/// Every such type is an [`Argument`] as well. Sets of such types can be parsed in
+/// unit tests; refer to [`Arguments`].
+pub trait FromSegments: Sized {
+    /// The error that might occur when parsing the argument.
+    type Error;
+
+    /// Parses the argument out of the segments.
+    ///
+    /// Refer to [`Argument::parse`].
+    fn from_segments(segments: &mut ArgumentSegments<'_>) -> Result<Self, Self::Error>;
+
+    /// Returns the names of the values the argument is chosen out of.
+    ///
+    /// Refer to [`Argument::choices`].
+    fn choices() -> &'static [&'static str] {
+        &[]
+    }
+}
+
+/// A set of arguments parsed together.
+///
+/// This is implemented by structs deriving the `Arguments` macro. Their fields
+/// are parsed in order, following the rules of the parameters of the [`command`]
+/// macro. If every field implements [`FromSegments`], the struct may derive the
+/// `FromSegments` macro as well, allowing it to be parsed without a [`Context`]:
+///
+/// ```rust
+/// use serenity_framework::argument::{Arguments, FromSegments};
+/// use serenity_framework::utils::ArgumentSegments;
+///
+/// #[derive(Debug, Arguments, FromSegments)]
+/// struct Transfer {
+///     amount: u64,
+///     #[rest]
+///     reason: String,
+/// }
+///
+/// let mut segments = ArgumentSegments::new("50 for lunch", " ");
+/// let transfer = Transfer::from_segments(&mut segments).unwrap();
+///
+/// assert_eq!(transfer.amount, 50);
+/// assert_eq!(transfer.reason, "for lunch");
+///
+/// let mut segments = ArgumentSegments::new("fifty", " ");
+/// let err = Transfer::from_segments(&mut segments).unwrap_err();
+///
+/// assert_eq!(err.parameter.unwrap().name, "amount");
+/// ```
+///
+/// A command takes a set of arguments as a single parameter marked with
+/// `#[arguments]`, in place of any other positional parameter:
+///
+/// ```rust
+/// use serenity::model::channel::Message;
+/// use serenity_framework::prelude::*;
+///
+/// #[derive(Arguments)]
+/// struct Transfer {
+///     amount: u64,
+///     #[rest]
+///     reason: String,
+/// }
+///
+/// #[command]
+/// async fn pay(
+///     _ctx: FrameworkContext,
+///     _msg: &Message,
+///     #[arguments] transfer: Transfer,
+/// ) -> CommandResult {
+///     assert_eq!(transfer.amount, 50);
+///     assert_eq!(transfer.reason, "for lunch");
+///     Ok(())
+/// }
+///
+/// # async fn dispatch<D: Send + Sync, E: std::fmt::Display>(
+/// #     framework: &Framework<D, E>,
+/// #     content: &str,
+/// # ) -> Result<(), String> {
+/// #     use std::sync::Arc;
+/// #     use serenity::prelude::{RwLock, TypeMap};
+/// #     let ctx = serenity::client::Context {
+/// #         data: Arc::new(RwLock::new(TypeMap::new())),
+/// #         shard: serenity::client::bridge::gateway::ShardMessenger::new(
+/// #             serenity::futures::channel::mpsc::unbounded().0,
+/// #         ),
+/// #         shard_id: 0,
+/// #         http: Arc::new(serenity::http::Http::new_with_token("")),
+/// #         cache: Arc::new(serenity::cache::Cache::new()),
+/// #     };
+/// #     let msg = serde_json::from_value(serde_json::json!({
+/// #         "id": "1", "channel_id": "1", "type": 0, "content": content,
+/// #         "author": { "id": "1", "username": "user", "discriminator": "0001", "avatar": null },
+/// #         "attachments": [], "embeds": [], "mentions": [], "mention_roles": [],
+/// #         "mention_everyone": false, "pinned": false, "tts": false,
+/// #         "timestamp": "2021-01-01T00:00:00Z",
+/// #     }));
+/// #     framework.dispatch(&ctx, &msg.unwrap()).await.map_err(|e| e.to_string())
+/// # }
+/// # #[tokio::main(flavor = "current_thread")]
+/// # async fn main() {
+/// let mut conf: Configuration = Configuration::new();
+/// conf.prefix("!").command(pay);
+/// let framework: Framework = Framework::new(conf);
+///
+/// // `dispatch` invokes the framework with a message, returning its error as text.
+/// assert_eq!(dispatch(&framework, "!pay 50 for lunch").await, Ok(()));
+///
+/// let err = dispatch(&framework, "!pay fifty for lunch").await.unwrap_err();
+/// assert_eq!(err, "argument 1 (`amount`): `fifty`: invalid digit found in string");
+/// # }
+/// ```
+///
+/// [`command`]: crate::prelude::command
+pub trait Arguments<D = DefaultData, E = DefaultError>: Sized {
+    /// Parses the set of arguments out of the segments.
+    ///
+    /// Errors describe the field that failed to be parsed as the [parameter].
+    ///
+    /// [parameter]: ArgumentError::parameter
+    fn parse_arguments<'a>(
+        ctx: &'a Context<D, E>,
+        msg: &'a Message,
+        segments: &'a mut ArgumentSegments<'_>,
+    ) -> BoxFuture<'a, Result<Self, ArgumentError<BoxedError>>>;
+}
+
+/// An adapter parsing any type implementing [`FromStr`] as an [`Argument`].
+///
+/// The type is parsed out of a single segment.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct FromStrArgument<T>(pub T);
+
+impl<T> FromStrArgument<T> {
+    /// Returns the parsed value.
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T: FromStr> FromSegments for FromStrArgument<T> {
+    type Error = T::Err;
+
+    fn from_segments(segments: &mut ArgumentSegments<'_>) -> Result<Self, Self::Error> {
+        T::from_str(&segments.next().unwrap_or_default()).map(FromStrArgument)
+    }
+}
+
+#[async_trait]
+impl<D, E, T> Argument<D, E> for T
+where
+    D: Send + Sync,
+    E: Send + Sync,
+    T: FromSegments,
+{
+    type Error = T::Error;
+
+    async fn parse(
+        _: &Context<D, E>,
+        _: &Message,
+        segments: &mut ArgumentSegments<'_>,
+    ) -> Result<Self, Self::Error> {
+        T::from_segments(segments)
+    }
+
+    fn choices() -> &'static [&'static str] {
+        <T as FromSegments>::choices()
+    }
+}
+
+/// Arguments parsed out of as many segments as possible.
+///
+/// As a parameter of a command, segments are parsed into arguments until one
+/// fails to be parsed. The remaining segments are left to the next parameters.
+/// Arguments are given back if too few segments remain for the required
+/// parameters that follow. Refer to [`reserved_greedy_arguments`].
+///
+/// # Examples
+///
+/// ```rust
+/// use serenity::model::channel::Message;
+/// use serenity_framework::prelude::*;
+///
+/// #[command]
+/// async fn sum(
+///     _ctx: FrameworkContext,
+///     _msg: &Message,
+///     terms: Greedy<u32>,
+///     total: u32,
+/// ) -> CommandResult {
+///     assert_eq!(terms.iter().sum::<u32>(), total);
+///     Ok(())
+/// }
+///
+/// # async fn dispatch<D: Send + Sync, E: std::fmt::Display>(
+/// #     framework: &Framework<D, E>,
+/// #     content: &str,
+/// # ) -> Result<(), String> {
+/// #     use std::sync::Arc;
+/// #     use serenity::prelude::{RwLock, TypeMap};
+/// #     let ctx = serenity::client::Context {
+/// #         data: Arc::new(RwLock::new(TypeMap::new())),
+/// #         shard: serenity::client::bridge::gateway::ShardMessenger::new(
+/// #             serenity::futures::channel::mpsc::unbounded().0,
+/// #         ),
+/// #         shard_id: 0,
+/// #         http: Arc::new(serenity::http::Http::new_with_token("")),
+/// #         cache: Arc::new(serenity::cache::Cache::new()),
+/// #     };
+/// #     let msg = serde_json::from_value(serde_json::json!({
+/// #         "id": "1", "channel_id": "1", "type": 0, "content": content,
+/// #         "author": { "id": "1", "username": "user", "discriminator": "0001", "avatar": null },
+/// #         "attachments": [], "embeds": [], "mentions": [], "mention_roles": [],
+/// #         "mention_everyone": false, "pinned": false, "tts": false,
+/// #         "timestamp": "2021-01-01T00:00:00Z",
+/// #     }));
+/// #     framework.dispatch(&ctx, &msg.unwrap()).await.map_err(|e| e.to_string())
+/// # }
+/// # #[tokio::main(flavor = "current_thread")]
+/// # async fn main() {
+/// let mut conf: Configuration = Configuration::new();
+/// conf.prefix("!").command(sum);
+/// let framework: Framework = Framework::new(conf);
+///
+/// // `dispatch` invokes the framework with a message, returning its error as text.
+/// // The last segment is left to `total`, although it could be parsed into `terms`.
+/// assert_eq!(dispatch(&framework, "!sum 1 2 3").await, Ok(()));
+/// assert_eq!(dispatch(&framework, "!sum 7 7").await, Ok(()));
+///
+/// let err = dispatch(&framework, "!sum 1 2 three").await.unwrap_err();
+/// assert_eq!(err, "argument 2 (`total`): `three`: invalid digit found in string");
+/// # }
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct Greedy<T>(pub Vec<T>);
+
+impl<T> Greedy<T> {
+    /// Returns the parsed arguments.
+    pub fn into_inner(self) -> Vec<T> {
+        self.0
+    }
+}
+
+impl<T> Deref for Greedy<T> {
+    type Target = Vec<T>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T> DerefMut for Greedy<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+macro_rules! impl_from_str_argument {
+    ($($ty:ty),* $(,)?) => {
+        $(
+            impl FromSegments for $ty {
+                type Error = <$ty as FromStr>::Err;
+
+                fn from_segments(segments: &mut ArgumentSegments<'_>) -> Result<Self, Self::Error> {
+                    <$ty>::from_str(&segments.next().unwrap_or_default())
+                }
+            }
+        )*
+    };
+}
+
+impl_from_str_argument! {
+    char, String, PathBuf,
+    i8, i16, i32, i64, i128, isize,
+    u8, u16, u32, u64, u128, usize,
+    f32, f64,
+    NonZeroI8, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI128, NonZeroIsize,
+    NonZeroU8, NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU128, NonZeroUsize,
+    IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr, SocketAddrV4, SocketAddrV6,
+}
+
+/// Parses an argument out of the next segments.
+///
+/// On failure, the error describes the consumed segments.
+async fn parse_segments<P>(
+    segments: &mut ArgumentSegments<'_>,
+    parser: &mut P,
+) -> Result<P::Output, ArgumentError<P::Error>>
+where
+    P: Parser,
+{
+    let start = segments.offset();
+    let source = segments.source();
+
+    check_quotes(segments)?;
+
+    parser.parse(segments).await.map_err(|err| consumed(err, segments, source, start))
+}
+
+/// Checks whether the next segment is missing its closing quotation mark.
+fn check_quotes<E>(segments: &ArgumentSegments<'_>) -> Result<(), ArgumentError<E>> {
+    let start = segments.offset();
+    let source = segments.source();
+
+    segments.check_quotes().map_err(|err| {
+        let kind = ArgumentErrorKind::UnbalancedQuote(err);
+        ArgumentError::new(kind, source, start..(start + source.len()))
+    })
+}
+
+/// Returns an error for the segments consumed since the `source` at the `start` offset.
+fn consumed<E>(
+    err: E,
+    segments: &ArgumentSegments<'_>,
+    source: &str,
+    start: usize,
+) -> ArgumentError<E> {
+    let (input, span) = consumed_input(segments, source, start);
+
+    ArgumentError::new(ArgumentErrorKind::Argument(err), input, span)
+}
+
+/// Returns the segments consumed since the `source` at the `start` offset, along
+/// with their span.
+fn consumed_input<'a>(
+    segments: &ArgumentSegments<'_>,
+    source: &'a str,
+    start: usize,
+) -> (&'a str, Range<usize>) {
+    let consumed = &source[..(source.len() - segments.source().len())];
+    let input = consumed.trim_end_matches(segments.delimiter());
+
+    (input, start..(start + input.len()))
+}
+
+/// Returns an error for the remainder of the segments.
+fn remainder<E>(err: E, source: &str, start: usize) -> ArgumentError<E> {
+    ArgumentError::new(ArgumentErrorKind::Argument(err), source, start..(start + source.len()))
+}
+
+/// Returns an error for an argument missing at the end of the segments.
+fn missing<E>(segments: &ArgumentSegments<'_>) -> ArgumentError<E> {
+    let end = segments.offset();
+
+    ArgumentError::new(ArgumentErrorKind::Missing, "", end..end)
+}
+
+/// Parses an argument out of a list of segments.
+///
+/// # Errors
+///
+/// - If the list of segments is empty, [`ArgumentErrorKind::Missing`] is returned.
+/// - If the next segment is missing its closing quotation mark,
+///   [`ArgumentErrorKind::UnbalancedQuote`] is returned.
+/// - If the segments cannot be parsed into an argument, [`ArgumentErrorKind::Argument`] is
+///   returned.
+pub async fn required_argument<P>(
+    segments: &mut ArgumentSegments<'_>,
+    mut parser: P,
+) -> Result<P::Output, ArgumentError<P::Error>>
+where
+    P: Parser,
+{
+    if segments.is_empty() {
+        return Err(missing(segments));
+    }
+
+    parse_segments(segments, &mut parser).await
+}
+
+/// Tries to parse an argument out of a list of segments.
+///
+/// If the list of segments is empty, `Ok(None)` is returned. Otherwise,
+/// the segments are parsed into an argument. If parsing succeeds,
+/// `Ok(Some(...))` is returned. If it fails, the argument is skipped: the
+/// segments are [rewound][rewind] and `Ok(None)` is returned, leaving them
+/// to the next parameters.
+///
+/// # Errors
+///
+/// If the next segment is missing its closing quotation mark,
+/// [`ArgumentErrorKind::UnbalancedQuote`] is returned.
+///
+/// [rewind]: ArgumentSegments::rewind
+pub async fn optional_argument<P>(
+    segments: &mut ArgumentSegments<'_>,
+    mut parser: P,
+) -> Result<Option<P::Output>, ArgumentError<P::Error>>
+where
+    P: Parser,
+{
+    if segments.is_empty() {
+        return Ok(None);
+    }
+
+    check_quotes(segments)?;
+
+    let checkpoint = segments.checkpoint();
+
+    match parser.parse(segments).await {
+        Ok(argument) => Ok(Some(argument)),
+        Err(_) => {
+            segments.rewind(checkpoint);
+
+            Ok(None)
+        },
+    }
+}
+
+/// Tries to parse many arguments from a list of segments.
+///
+/// Arguments are parsed until the list is exhausted. If parsing all arguments
+/// succeeds, the vector is returned. Otherwise, the first error is returned.
+/// The error is of the [`ArgumentErrorKind::Argument`] kind, or of the
+/// [`ArgumentErrorKind::UnbalancedQuote`] kind if a segment is missing its
+/// closing quotation mark.
+pub async fn variadic_arguments<P>(
+    segments: &mut ArgumentSegments<'_>,
+    mut parser: P,
+) -> Result<Vec<P::Output>, ArgumentError<P::Error>>
+where
+    P: Parser,
+{
+    let mut arguments = Vec::new();
+
+    while !segments.is_empty() {
+        let len = segments.source().len();
+
+        arguments.push(parse_segments(segments, &mut parser).await?);
+
+        // Avoid looping forever on arguments that do not take any segments.
+        if segments.source().len() == len {
+            break;
+        }
+    }
+
+    Ok(arguments)
+}
+
+/// Parses as many arguments as possible from a list of segments.
+///
+/// Arguments are parsed until the list is exhausted or an argument fails to
+/// be parsed. The segments of the failed argument are [rewound][rewind], leaving
+/// them to the next parameters. The arguments parsed until then are returned,
+/// which might be none.
+///
+/// # Errors
+///
+/// If a segment is missing its closing quotation mark,
+/// [`ArgumentErrorKind::UnbalancedQuote`] is returned.
+///
+/// [rewind]: ArgumentSegments::rewind
+pub async fn greedy_arguments<P>(
+    segments: &mut ArgumentSegments<'_>,
+    parser: P,
+) -> Result<Greedy<P::Output>, ArgumentError<P::Error>>
+where
+    P: Parser,
+{
+    reserved_greedy_arguments(segments, parser, 0).await
+}
+
+/// Parses as many arguments as possible from a list of segments, leaving
+/// some segments to the next parameters.
+///
+/// Arguments are parsed like in [`greedy_arguments`]. Afterwards, the last
+/// arguments are given back, and their segments [rewound][rewind], until at
+/// least `reserved` segments remain. This lets required parameters following
+/// a greedy parameter receive a segment, even if it could have been parsed
+/// by the greedy parameter.
+///
+/// # Errors
+///
+/// If a segment is missing its closing quotation mark,
+/// [`ArgumentErrorKind::UnbalancedQuote`] is returned.
+///
+/// [rewind]: ArgumentSegments::rewind
+pub async fn reserved_greedy_arguments<P>(
+    segments: &mut ArgumentSegments<'_>,
+    mut parser: P,
+    reserved: usize,
+) -> Result<Greedy<P::Output>, ArgumentError<P::Error>>
+where
+    P: Parser,
+{
+    let mut arguments = Vec::new();
+    let mut checkpoints = Vec::new();
+
+    while !segments.is_empty() {
+        check_quotes(segments)?;
+
+        let checkpoint = segments.checkpoint();
+        let len = segments.source().len();
+
+        match parser.parse(segments).await {
+            Ok(argument) => {
+                arguments.push(argument);
+                checkpoints.push(checkpoint);
+            },
+            Err(_) => {
+                segments.rewind(checkpoint);
+                break;
+            },
+        }
+
+        // Avoid looping forever on arguments that do not take any segments.
+        if segments.source().len() == len {
+            break;
+        }
+    }
+
+    while segments.clone().count() < reserved {
+        match checkpoints.pop() {
+            Some(checkpoint) => {
+                arguments.pop();
+                segments.rewind(checkpoint);
+            },
+            None => break,
+        }
+    }
+
+    Ok(Greedy(arguments))
+}
+
+/// Parses the remainder of the list of segments into an argument.
+///
+/// All segments (even if none) are treated as a single segment
+/// and parsed to the specified argument type. If parsing success,
+/// `Ok(...)` is returned, otherwise `Err(...)`. The error is of the
+/// [`ArgumentErrorKind::Argument`] kind.
+pub async fn rest_argument<P>(
+    segments: &mut ArgumentSegments<'_>,
+    mut parser: P,
+) -> Result<P::Output, ArgumentError<P::Error>>
+where
+    P: Parser,
+{
+    let start = segments.offset();
+    let source = segments.source();
+
+    // An empty delimiter makes the whole source a single segment.
+    let mut rest = ArgumentSegments::new(source, "");
+
+    let argument = parser.parse(&mut rest).await.map_err(|err| remainder(err, source, start))?;
+
+    segments.set_source(rest.source());
+
+    Ok(argument)
+}
+
+/// Checks that no arguments are left over in the list of segments.
+///
+/// This is used by the [`command`] macro if [strict mode][strict] is enabled
+/// for the command.
+///
+/// # Errors
+///
+/// If any arguments are left over, [`ArgumentErrorKind::TooMany`] is returned,
+/// with the leftover text as the input.
+///
+/// # Examples
+///
+/// ```rust
+/// use serenity_framework::argument::check_extraneous;
+/// use serenity_framework::utils::ArgumentSegments;
+///
+/// let mut segments = ArgumentSegments::new("@a @b  @c ", " ");
+/// segments.next();
+///
+/// let err = check_extraneous(&segments).unwrap_err();
+/// assert_eq!(err.input, "@b  @c");
+/// assert_eq!(err.span, 3..9);
+/// assert_eq!(err.to_string(), "`@b  @c`: too many arguments");
+///
+/// segments.next();
+/// segments.next();
+/// assert!(check_extraneous(&segments).is_ok());
+/// ```
+///
+/// [`command`]: crate::prelude::command
+/// [strict]: crate::configuration::Configuration::strict_arguments
+pub fn check_extraneous(segments: &ArgumentSegments<'_>) -> Result<(), ArgumentError<Infallible>> {
+    let source = segments.source();
+    let input = source.trim();
+
+    if input.is_empty() {
+        return Ok(());
+    }
+
+    let start = segments.offset() + (source.len() - source.trim_start().len());
+
+    Err(ArgumentError::new(ArgumentErrorKind::TooMany, input, start..(start + input.len())))
+}
+
+/// Checks that the amount of arguments parsed for a parameter is within bounds.
+///
+/// The arguments were parsed out of the segments since the `source` at the `start`
+/// offset. This is used by the [`command`] macro for parameters marked with
+/// `#[count(min = ..., max = ...)]`.
+///
+/// # Errors
+///
+/// - If less than `min` arguments were parsed, [`ArgumentErrorKind::Missing`] is returned.
+/// - If more than `max` arguments were parsed, [`ArgumentErrorKind::TooMany`] is returned,
+///   with the consumed segments as the input.
+///
+/// # Examples
+///
+/// ```rust
+/// use serenity_framework::argument::{check_count, ArgumentErrorKind};
+/// use serenity_framework::utils::ArgumentSegments;
+///
+/// let mut segments = ArgumentSegments::new("@a @b @c", " ");
+/// let source = segments.source();
+/// let values = segments.by_ref().collect::<Vec<_>>();
+///
+/// assert!(check_count(values.len(), 1, 3, &segments, source, 0).is_ok());
+///
+/// let err = check_count(values.len(), 1, 2, &segments, source, 0).unwrap_err();
+/// assert!(matches!(err.kind, ArgumentErrorKind::TooMany));
+/// assert_eq!(err.input, "@a @b @c");
+///
+/// let err = check_count(values.len(), 4, 10, &segments, source, 0).unwrap_err();
+/// assert!(matches!(err.kind, ArgumentErrorKind::Missing));
+/// ```
+///
+/// [`command`]: crate::prelude::command
+pub fn check_count(
+    len: usize,
+    min: usize,
+    max: usize,
+    segments: &ArgumentSegments<'_>,
+    source: &str,
+    start: usize,
+) -> Result<(), ArgumentError<Infallible>> {
+    if len < min {
+        return Err(missing(segments));
+    }
+
+    if len > max {
+        let (input, span) = consumed_input(segments, source, start);
+
+        return Err(ArgumentError::new(ArgumentErrorKind::TooMany, input, span));
+    }
+
+    Ok(())
+}
+
+/// Parses an option out of a list of named arguments.
+///
+/// Refer to [`NamedArguments::option`] for how options are given.
+///
+/// # Errors
+///
+/// - If the option is absent, [`ArgumentErrorKind::Missing`] is returned.
+/// - If the option is given without a value, [`ArgumentErrorKind::MissingValue`] is
+///   returned.
+/// - If the value cannot be parsed into an argument, [`ArgumentErrorKind::Argument`] is
+///   returned.
+pub async fn required_option<D, E, T>(
+    ctx: &Context<D, E>,
+    msg: &Message,
+    named: &mut NamedArguments<'_>,
+    long: &str,
+    short: Option<char>,
+) -> Result<T, ArgumentError<T::Error>>
+where
+    T: Argument<D, E>,
+{
+    let end = named.source().len();
+
+    optional_option(ctx, msg, named, long, short)
+        .await?
+        .ok_or_else(|| ArgumentError::new(ArgumentErrorKind::Missing, "", end..end))
+}
+
+/// Tries to parse an option out of a list of named arguments.
+///
+/// If the option is absent, `Ok(None)` is returned. Otherwise, its value is
+/// parsed into an argument. If parsing succeeds, `Ok(Some(...))` is returned,
+/// otherwise `Err(...)`. The error is of the [`ArgumentErrorKind::Argument`] kind,
+/// or of the [`ArgumentErrorKind::MissingValue`] kind if the option is given
+/// without a value.
+pub async fn optional_option<D, E, T>(
+    ctx: &Context<D, E>,
+    msg: &Message,
+    named: &mut NamedArguments<'_>,
+    long: &str,
+    short: Option<char>,
+) -> Result<Option<T>, ArgumentError<T::Error>>
+where
+    T: Argument<D, E>,
+{
+    let end = named.source().len();
+
+    let (value, span) = match named.option_with_span(long, short) {
+        Ok(Some(pair)) => pair,
+        Ok(None) => return Ok(None),
+        Err(err) => {
+            return Err(ArgumentError::new(ArgumentErrorKind::MissingValue(err), "", end..end))
+        },
+    };
+
+    // An empty delimiter makes the whole value a single segment.
+    let mut segments = ArgumentSegments::new(&value, "");
+
+    T::parse(ctx, msg, &mut segments).await.map(Some).map_err(|err| {
+        let input = &named.source()[span.clone()];
+
+        ArgumentError::new(ArgumentErrorKind::Argument(err), input, span)
+    })
+}
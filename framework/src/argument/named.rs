@@ -0,0 +1,407 @@
+//! Parsing of named arguments.
+//!
+//! Named arguments are given by name anywhere in the arguments of a command,
+//! unlike positional arguments, which are given in a fixed order. They come in
+//! two forms:
+//!
+//! - *flags*, switches that are either present or absent: `--silent` or `-s`.
+//! - *options*, named values: `--days 7`, `--days=7`, `-n 7` or `-n=7`.
+//!
+//! Named arguments are extracted before positional arguments are parsed.
+//! A lone `--` ends named arguments; everything after it is positional.
+//!
+//! The [`command`] macro extracts parameters marked with `#[flag]` as flags,
+//! which must be of type `bool`, and parameters marked with `#[option]` as options,
+//! which are required unless wrapped in an `Option`. Their long name is the name
+//! of the parameter, with underscores replaced by hyphens. Both attributes accept
+//! another long name, `name = "..."`, and a short name, `short = '...'`.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use serenity::model::channel::Message;
+//! use serenity_framework::prelude::*;
+//!
+//! #[command]
+//! async fn ban(
+//!     _ctx: FrameworkContext,
+//!     _msg: &Message,
+//!     #[flag(short = 's')] silent: bool,
+//!     #[option(short = 'n')] days: Option<u8>,
+//!     #[option(name = "why")] reason: String,
+//!     user: String,
+//! ) -> CommandResult {
+//!     assert!(silent);
+//!     assert_eq!(days, Some(7));
+//!     assert_eq!(reason, "being rude");
+//!     assert_eq!(user, "@user");
+//!     Ok(())
+//! }
+//!
+//! # async fn dispatch<D: Send + Sync, E: std::fmt::Display>(
+//! #     framework: &Framework<D, E>,
+//! #     content: &str,
+//! # ) -> Result<(), String> {
+//! #     use std::sync::Arc;
+//! #     use serenity::prelude::{RwLock, TypeMap};
+//! #     let ctx = serenity::client::Context {
+//! #         data: Arc::new(RwLock::new(TypeMap::new())),
+//! #         shard: serenity::client::bridge::gateway::ShardMessenger::new(
+//! #             serenity::futures::channel::mpsc::unbounded().0,
+//! #         ),
+//! #         shard_id: 0,
+//! #         http: Arc::new(serenity::http::Http::new_with_token("")),
+//! #         cache: Arc::new(serenity::cache::Cache::new()),
+//! #     };
+//! #     let msg = serde_json::from_value(serde_json::json!({
+//! #         "id": "1", "channel_id": "1", "type": 0, "content": content,
+//! #         "author": { "id": "1", "username": "user", "discriminator": "0001", "avatar": null },
+//! #         "attachments": [], "embeds": [], "mentions": [], "mention_roles": [],
+//! #         "mention_everyone": false, "pinned": false, "tts": false,
+//! #         "timestamp": "2021-01-01T00:00:00Z",
+//! #     }));
+//! #     framework.dispatch(&ctx, &msg.unwrap()).await.map_err(|e| e.to_string())
+//! # }
+//! # #[tokio::main(flavor = "current_thread")]
+//! # async fn main() {
+//! let mut conf: Configuration = Configuration::new();
+//! conf.prefix("!").command(ban);
+//! let framework: Framework = Framework::new(conf);
+//!
+//! // `dispatch` invokes the framework with a message, returning its error as text.
+//! assert_eq!(dispatch(&framework, "!ban @user -n 7 --why=\"being rude\" -s").await, Ok(()));
+//!
+//! let err = dispatch(&framework, "!ban @user -n seven --why spam").await.unwrap_err();
+//! assert_eq!(err, "`days`: `seven`: invalid digit found in string");
+//!
+//! let err = dispatch(&framework, "!ban @user -n 7").await.unwrap_err();
+//! assert_eq!(err, "`reason`: missing required argument");
+//! # }
+//! ```
+//!
+//! [`command`]: crate::prelude::command
+
+use std::borrow::Cow;
+use std::error::Error as StdError;
+use std::fmt;
+use std::ops::Range;
+
+use crate::utils::argument_segment_split;
+
+/// A value of an option, along with its byte span in the source.
+pub type SpannedValue<'a> = (Cow<'a, str>, Range<usize>);
+
+/// Error that occurs when an option is given without a value.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MissingValue {
+    /// The long name of the option.
+    pub name: String,
+}
+
+impl fmt::Display for MissingValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "missing value for option `--{}`", self.name)
+    }
+}
+
+impl StdError for MissingValue {}
+
+#[derive(Debug, Clone)]
+struct Token<'a> {
+    /// The source of the token, including its trailing delimiters.
+    raw: &'a str,
+    /// The byte index of the token in the source.
+    start: usize,
+    /// The name of the token, if it looks like `--name` or `-n`.
+    name: Option<&'a str>,
+    /// The value of the token, or the value after `=` if it is named.
+    value: Option<Cow<'a, str>>,
+    used: bool,
+}
+
+impl<'a> Token<'a> {
+    fn is_terminator(&self) -> bool {
+        self.name == Some("--") && self.value.is_none()
+    }
+
+    fn matches(&self, long: &str, short: Option<char>) -> bool {
+        let name = match self.name {
+            Some(name) if !self.used => name,
+            _ => return false,
+        };
+
+        if let Some(name) = name.strip_prefix("--") {
+            return name == long;
+        }
+
+        let mut chars = name[1..].chars();
+        short.is_some() && chars.next() == short && chars.next().is_none()
+    }
+
+    fn text(&self, delimiter: &str) -> Cow<'a, str> {
+        match (&self.name, &self.value) {
+            (None, Some(value)) => value.clone(),
+            _ => Cow::Borrowed(self.raw.trim_end_matches(delimiter)),
+        }
+    }
+
+    /// Returns the span of the token in the source, excluding trailing delimiters.
+    fn span(&self, delimiter: &str) -> Range<usize> {
+        self.start..(self.start + self.raw.trim_end_matches(delimiter).len())
+    }
+}
+
+/// A list of arguments from which named arguments are extracted.
+///
+/// Extracted arguments are removed from the list. The remaining arguments
+/// are [positional].
+///
+/// # Examples
+///
+/// ```rust
+/// use serenity_framework::argument::NamedArguments;
+/// use serenity_framework::utils::DEFAULT_QUOTES;
+///
+/// let args = "@user --days 7 --reason=\"being rude\" -s";
+/// let mut named = NamedArguments::new(args, " ", DEFAULT_QUOTES);
+///
+/// assert!(named.flag("silent", Some('s')));
+/// assert!(!named.flag("force", Some('f')));
+/// assert_eq!(named.option("days", Some('n')).unwrap().as_deref(), Some("7"));
+/// assert_eq!(named.option("reason", None).unwrap().as_deref(), Some("being rude"));
+/// assert_eq!(named.positional(), "@user ");
+/// ```
+///
+/// Arguments after `--` are never extracted:
+///
+/// ```rust
+/// use serenity_framework::argument::NamedArguments;
+/// use serenity_framework::utils::DEFAULT_QUOTES;
+///
+/// let mut named = NamedArguments::new("-n 5 -- -n 3", " ", DEFAULT_QUOTES);
+///
+/// assert_eq!(named.option("count", Some('n')).unwrap().as_deref(), Some("5"));
+/// assert_eq!(named.positional(), "-n 3");
+///
+/// let mut named = NamedArguments::new("--days", " ", DEFAULT_QUOTES);
+///
+/// assert!(named.option("days", None).is_err());
+/// ```
+///
+/// [positional]: Self::positional
+#[derive(Debug, Clone)]
+pub struct NamedArguments<'a> {
+    src: &'a str,
+    delimiter: &'a str,
+    tokens: Vec<Token<'a>>,
+    /// The index of the `--` token, or the amount of tokens if it is absent.
+    end: usize,
+}
+
+impl<'a> NamedArguments<'a> {
+    /// Splits the source into arguments using a delimiter and a set of quotation marks.
+    pub fn new(src: &'a str, delimiter: &'a str, quotes: &[(char, char)]) -> Self {
+        let source = src;
+        let len = src.len();
+        let mut tokens = Vec::new();
+        let mut src = src;
+
+        while !src.is_empty() {
+            let (name, body) = match named_prefix(src, delimiter) {
+                Some((name, after)) => match after.strip_prefix('=') {
+                    Some(body) => (Some(name), body),
+                    None => {
+                        let rest = after.trim_start_matches(delimiter);
+
+                        tokens.push(Token {
+                            raw: &src[..(src.len() - rest.len())],
+                            start: len - src.len(),
+                            name: Some(name),
+                            value: None,
+                            used: false,
+                        });
+
+                        src = rest;
+                        continue;
+                    },
+                },
+                None => (None, src),
+            };
+
+            let (name, value, rest) = match argument_segment_split(body, delimiter, quotes) {
+                Ok(Some((value, rest))) => (name, value, rest),
+                Ok(None) => (name, Cow::Borrowed(""), ""),
+                // Leave the unbalanced quote to positional arguments.
+                Err(_) => (None, Cow::Borrowed(src), ""),
+            };
+
+            tokens.push(Token {
+                raw: &src[..(src.len() - rest.len())],
+                start: len - src.len(),
+                name,
+                value: Some(value),
+                used: false,
+            });
+
+            src = rest;
+        }
+
+        let end = tokens.iter().position(Token::is_terminator).unwrap_or(tokens.len());
+
+        Self {
+            src: source,
+            delimiter,
+            tokens,
+            end,
+        }
+    }
+
+    /// Returns the source string from which arguments are extracted.
+    pub fn source(&self) -> &'a str {
+        self.src
+    }
+
+    /// Extracts a flag by its long name, or its short name.
+    ///
+    /// Returns a boolean indicating whether the flag is present.
+    pub fn flag(&mut self, long: &str, short: Option<char>) -> bool {
+        let mut present = false;
+
+        for token in &mut self.tokens[..self.end] {
+            if token.value.is_none() && token.matches(long, short) {
+                token.used = true;
+                present = true;
+            }
+        }
+
+        present
+    }
+
+    /// Extracts the value of an option by its long name, or its short name.
+    ///
+    /// The value either follows the name after `=`, or is the next argument.
+    /// If the option is given many times, the last value is returned.
+    ///
+    /// # Errors
+    ///
+    /// If the option is given without a value, [`MissingValue`] is returned.
+    pub fn option(
+        &mut self,
+        long: &str,
+        short: Option<char>,
+    ) -> Result<Option<Cow<'a, str>>, MissingValue> {
+        self.option_with_span(long, short).map(|value| value.map(|(value, _)| value))
+    }
+
+    /// Extracts the value of an option, along with the byte span of the value
+    /// in the source.
+    ///
+    /// Refer to [`option`] for how options are given.
+    ///
+    /// [`option`]: Self::option
+    pub fn option_with_span(
+        &mut self,
+        long: &str,
+        short: Option<char>,
+    ) -> Result<Option<SpannedValue<'a>>, MissingValue> {
+        let mut result = None;
+
+        for i in 0..self.end {
+            if !self.tokens[i].matches(long, short) {
+                continue;
+            }
+
+            self.tokens[i].used = true;
+
+            if let Some(value) = &self.tokens[i].value {
+                let span = self.tokens[i].span(self.delimiter);
+                let name_len = self.tokens[i].name.map_or(0, |name| name.len() + 1);
+
+                result = Some((value.clone(), (span.start + name_len)..span.end));
+                continue;
+            }
+
+            let next = self.tokens[(i + 1)..self.end].first_mut().filter(|token| {
+                !token.used && token.name.is_none_or(|name| !name.starts_with("--"))
+            });
+
+            match next {
+                Some(token) => {
+                    token.used = true;
+                    result = Some((token.text(self.delimiter), token.span(self.delimiter)));
+                },
+                None => {
+                    return Err(MissingValue {
+                        name: long.to_string(),
+                    })
+                },
+            }
+        }
+
+        Ok(result)
+    }
+
+    /// Returns the arguments that were not extracted, without the `--` token.
+    pub fn positional(&self) -> String {
+        self.tokens
+            .iter()
+            .enumerate()
+            .filter(|(i, token)| !token.used && *i != self.end)
+            .map(|(_, token)| token.raw)
+            .collect()
+    }
+
+    /// Converts a byte index in the [positional] arguments to the byte index
+    /// in the source.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity_framework::argument::NamedArguments;
+    /// use serenity_framework::utils::DEFAULT_QUOTES;
+    ///
+    /// let mut named = NamedArguments::new("--force 5 apples", " ", DEFAULT_QUOTES);
+    ///
+    /// assert!(named.flag("force", None));
+    /// assert_eq!(named.positional(), "5 apples");
+    /// assert_eq!(named.source_position(2), 10);
+    /// ```
+    ///
+    /// [positional]: Self::positional
+    pub fn source_position(&self, index: usize) -> usize {
+        let mut offset = 0;
+
+        for (i, token) in self.tokens.iter().enumerate() {
+            if token.used || i == self.end {
+                continue;
+            }
+
+            if index < offset + token.raw.len() {
+                return token.start + (index - offset);
+            }
+
+            offset += token.raw.len();
+        }
+
+        self.src.len()
+    }
+}
+
+/// Returns the name of a named argument and the source after it, if the source
+/// starts with a named argument.
+fn named_prefix<'a>(src: &'a str, delimiter: &str) -> Option<(&'a str, &'a str)> {
+    let end = match src.find(delimiter) {
+        Some(index) if !delimiter.is_empty() => index,
+        _ => src.len(),
+    };
+
+    let word = &src[..end];
+
+    if word.len() < 2 || !word.starts_with('-') {
+        return None;
+    }
+
+    let end = word.find('=').unwrap_or(end);
+
+    Some(src.split_at(end))
+}
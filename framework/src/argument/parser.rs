@@ -0,0 +1,128 @@
+//! Ways of parsing arguments out of segments.
+//!
+//! The [parsing functions][parsers] of the parent module take a [`Parser`],
+//! which parses a single argument. This allows the same rules to apply to
+//! every argument, whether it needs a context or not:
+//!
+//! - [`ArgumentParser`] parses types implementing [`Argument`], with a context.
+//! - [`SegmentsParser`] parses types implementing [`FromSegments`], without any context.
+//!   As nothing is awaited, the parsing functions may be [completed][complete] at once.
+//! - [`CustomParser`] parses a segment with a function, without any context.
+//!
+//! [parsers]: super::required_argument
+//! [`CustomParser`]: super::custom::CustomParser
+
+use std::future::Future;
+use std::marker::PhantomData;
+
+use serenity::async_trait;
+use serenity::futures::FutureExt;
+use serenity::model::channel::Message;
+
+use super::{Argument, FromSegments};
+use crate::context::Context;
+use crate::utils::ArgumentSegments;
+
+/// A way of parsing a single argument out of segments.
+#[async_trait]
+pub trait Parser: Send {
+    /// The type of the argument.
+    type Output;
+    /// The error that might occur when parsing the argument.
+    type Error;
+
+    /// Parses the argument out of the segments.
+    ///
+    /// Refer to [`Argument::parse`].
+    async fn parse(
+        &mut self,
+        segments: &mut ArgumentSegments<'_>,
+    ) -> Result<Self::Output, Self::Error>;
+}
+
+/// Parses types implementing [`Argument`].
+pub struct ArgumentParser<'a, D, E, T> {
+    ctx: &'a Context<D, E>,
+    msg: &'a Message,
+    _argument: PhantomData<fn() -> T>,
+}
+
+impl<'a, D, E, T> ArgumentParser<'a, D, E, T> {
+    /// Creates a parser with the context and message of a command.
+    pub fn new(ctx: &'a Context<D, E>, msg: &'a Message) -> Self {
+        Self {
+            ctx,
+            msg,
+            _argument: PhantomData,
+        }
+    }
+}
+
+#[async_trait]
+impl<'a, D, E, T> Parser for ArgumentParser<'a, D, E, T>
+where
+    D: Send + Sync,
+    E: Send + Sync,
+    T: Argument<D, E>,
+{
+    type Output = T;
+    type Error = T::Error;
+
+    async fn parse(&mut self, segments: &mut ArgumentSegments<'_>) -> Result<T, T::Error> {
+        T::parse(self.ctx, self.msg, segments).await
+    }
+}
+
+/// Parses types implementing [`FromSegments`], without any context.
+pub struct SegmentsParser<T>(PhantomData<fn() -> T>);
+
+impl<T> SegmentsParser<T> {
+    /// Creates a parser.
+    pub fn new() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<T> Default for SegmentsParser<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl<T> Parser for SegmentsParser<T>
+where
+    T: FromSegments,
+{
+    type Output = T;
+    type Error = T::Error;
+
+    async fn parse(&mut self, segments: &mut ArgumentSegments<'_>) -> Result<T, T::Error> {
+        T::from_segments(segments)
+    }
+}
+
+/// Returns the output of a future that does not wait on anything, such as
+/// parsing with a [`SegmentsParser`] or a [`CustomParser`].
+///
+/// # Panics
+///
+/// This function panics if the future is not ready when first polled.
+///
+/// # Examples
+///
+/// ```rust
+/// use serenity_framework::argument::parser::{complete, SegmentsParser};
+/// use serenity_framework::argument::variadic_arguments;
+/// use serenity_framework::utils::ArgumentSegments;
+///
+/// let mut segments = ArgumentSegments::new("1 2 3", " ");
+/// let numbers = complete(variadic_arguments(&mut segments, SegmentsParser::<u32>::new()));
+///
+/// assert_eq!(numbers.unwrap(), vec![1, 2, 3]);
+/// ```
+///
+/// [`CustomParser`]: super::custom::CustomParser
+pub fn complete<F: Future>(future: F) -> F::Output {
+    future.now_or_never().expect("the future waited on something")
+}
@@ -0,0 +1,233 @@
+//! Functions and types relating to buckets.
+//!
+//! A bucket limits how often a [command] can be invoked. It is registered under
+//! a name in the [configuration], and assigned to commands by that name. Many
+//! commands may share the same bucket, and thus share the same limits.
+//!
+//! The limits are expressed in two ways, which may be combined:
+//! - a *delay*, the minimal time between two invocations.
+//! - a *limit*, the maximal amount of invocations in a span of time.
+//!
+//! Invocations are counted separately for every user, channel, or guild,
+//! or counted together, depending on the [`Scope`] of the bucket.
+//!
+//! When a limit is exceeded, [dispatch] fails with [`DispatchError::RateLimited`].
+//!
+//! [command]: crate::command
+//! [configuration]: crate::configuration::Configuration::bucket
+//! [dispatch]: crate::Framework::dispatch
+//! [`DispatchError::RateLimited`]: crate::error::DispatchError::RateLimited
+
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use serenity::model::channel::Message;
+
+/// The scope of a [`Bucket`], determining how invocations are counted.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Scope {
+    /// Invocations are counted separately for every user.
+    ///
+    /// This is the default scope.
+    #[default]
+    User,
+    /// Invocations are counted separately for every channel.
+    Channel,
+    /// Invocations are counted separately for every guild.
+    ///
+    /// Invocations in direct messages are counted separately for every channel.
+    Guild,
+    /// Invocations are counted together.
+    Global,
+}
+
+impl Scope {
+    fn key(self, msg: &Message) -> u64 {
+        match self {
+            Scope::User => msg.author.id.0,
+            Scope::Channel => msg.channel_id.0,
+            Scope::Guild => msg.guild_id.map_or(msg.channel_id.0, |id| id.0),
+            Scope::Global => 0,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Usage {
+    last_use: Instant,
+    window_start: Instant,
+    uses: u32,
+}
+
+/// Data relating to a bucket.
+///
+/// Refer to the [module-level documentation][docs].
+///
+/// [docs]: crate::bucket
+#[derive(Default)]
+#[non_exhaustive]
+pub struct Bucket {
+    /// The minimal time between two invocations.
+    pub delay: Option<Duration>,
+    /// The maximal amount of invocations in a span of time.
+    pub limit: Option<(u32, Duration)>,
+    /// The scope of this bucket.
+    pub scope: Scope,
+    usages: Mutex<HashMap<u64, Usage>>,
+}
+
+impl Bucket {
+    /// Constructs a builder that will be used to create a bucket from scratch.
+    pub fn builder() -> BucketBuilder {
+        BucketBuilder::default()
+    }
+
+    /// Registers an invocation in this bucket.
+    ///
+    /// If the invocation exceeds the limits of this bucket, it is not registered
+    /// and the time after which the invocation would be allowed is returned.
+    pub fn take(&self, msg: &Message) -> Result<(), Duration> {
+        self.take_at(self.scope.key(msg), Instant::now())
+    }
+
+    /// Registers an invocation in this bucket at a point in time.
+    ///
+    /// Invocations are counted separately for every key. [`take`] uses the ID
+    /// of the user, channel, or guild, depending on the [scope].
+    ///
+    /// If the invocation exceeds the limits of this bucket, it is not registered
+    /// and the time after which the invocation would be allowed is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::time::{Duration, Instant};
+    ///
+    /// use serenity_framework::bucket::Bucket;
+    ///
+    /// let second = Duration::from_secs(1);
+    ///
+    /// let bucket = Bucket::builder().delay(second).limit(2, second * 10).build();
+    /// let start = Instant::now();
+    ///
+    /// assert_eq!(bucket.take_at(1, start), Ok(()));
+    /// // The delay has not passed.
+    /// assert_eq!(bucket.take_at(1, start + second / 2), Err(second / 2));
+    /// // Other keys are counted separately.
+    /// assert_eq!(bucket.take_at(2, start), Ok(()));
+    ///
+    /// assert_eq!(bucket.take_at(1, start + second), Ok(()));
+    /// // The limit is exhausted until the span has passed.
+    /// assert_eq!(bucket.take_at(1, start + second * 4), Err(second * 6));
+    ///
+    /// // The span has passed, resetting the limit.
+    /// assert_eq!(bucket.take_at(1, start + second * 10), Ok(()));
+    /// assert_eq!(bucket.take_at(1, start + second * 11), Ok(()));
+    /// assert_eq!(bucket.take_at(1, start + second * 12), Err(second * 8));
+    /// ```
+    ///
+    /// [`take`]: Self::take
+    /// [scope]: Self::scope
+    pub fn take_at(&self, key: u64, now: Instant) -> Result<(), Duration> {
+        let mut usages = self.usages.lock().unwrap();
+
+        // Forget invocations that can no longer affect the limits.
+        usages.retain(|_, usage| !self.expired(usage, now));
+
+        let usage = usages.entry(key).or_insert(Usage {
+            last_use: now,
+            window_start: now,
+            uses: 0,
+        });
+
+        if let Some(delay) = self.delay {
+            let elapsed = now - usage.last_use;
+
+            if usage.uses > 0 && elapsed < delay {
+                return Err(delay - elapsed);
+            }
+        }
+
+        if let Some((limit, span)) = self.limit {
+            if now - usage.window_start >= span {
+                usage.window_start = now;
+                usage.uses = 0;
+            }
+
+            if usage.uses >= limit {
+                return Err(span - (now - usage.window_start));
+            }
+        }
+
+        usage.last_use = now;
+        usage.uses = usage.uses.saturating_add(1);
+
+        Ok(())
+    }
+
+    fn expired(&self, usage: &Usage, now: Instant) -> bool {
+        let delay_passed = self.delay.is_none_or(|delay| now - usage.last_use >= delay);
+        let span_passed = self.limit.is_none_or(|(_, span)| now - usage.window_start >= span);
+
+        delay_passed && span_passed
+    }
+}
+
+impl Clone for Bucket {
+    fn clone(&self) -> Self {
+        Self {
+            delay: self.delay,
+            limit: self.limit,
+            scope: self.scope,
+            usages: Mutex::new(self.usages.lock().unwrap().clone()),
+        }
+    }
+}
+
+impl fmt::Debug for Bucket {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Bucket")
+            .field("delay", &self.delay)
+            .field("limit", &self.limit)
+            .field("scope", &self.scope)
+            .finish()
+    }
+}
+
+/// A builder type for creating a [`Bucket`] from scratch.
+#[derive(Debug, Clone, Default)]
+pub struct BucketBuilder {
+    inner: Bucket,
+}
+
+impl BucketBuilder {
+    /// Constructs a new instance of the builder.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Assigns the minimal time between two invocations.
+    pub fn delay(mut self, delay: Duration) -> Self {
+        self.inner.delay = Some(delay);
+        self
+    }
+
+    /// Assigns the maximal amount of invocations in a span of time.
+    pub fn limit(mut self, limit: u32, span: Duration) -> Self {
+        self.inner.limit = Some((limit, span));
+        self
+    }
+
+    /// Assigns the scope of the bucket.
+    pub fn scope(mut self, scope: Scope) -> Self {
+        self.inner.scope = scope;
+        self
+    }
+
+    /// Complete building a bucket.
+    pub fn build(self) -> Bucket {
+        self.inner
+    }
+}
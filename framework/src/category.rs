@@ -1,20 +1,62 @@
 //! A list of separate, but related commands.
 
+use std::fmt;
+
+use crate::check::Check;
 use crate::command::CommandId;
+use crate::{DefaultData, DefaultError};
 
 /// Grouping of independent commands with a related theme.
 ///
-/// This grouping, or "categorization" is not special in any way and
-/// does not affect invocation of commands. The type serves to simplify
-/// [registration of commands][register] and displaying commands together in help messages.
+/// This grouping, or "categorization" serves to simplify [registration of commands][register]
+/// and displaying commands together in help messages. It does not affect invocation of commands,
+/// except for its [`checks`], which apply to every command in the category.
 ///
 /// [register]: crate::configuration::Configuration::command
-#[derive(Debug, Default, Clone)]
-pub struct Category {
+/// [`checks`]: Self::checks
+#[non_exhaustive]
+pub struct Category<D = DefaultData, E = DefaultError> {
     /// Name of the category.
     pub name: String,
     /// [`Command`][cmd]s pertaining to this category.
     ///
     /// [cmd]: crate::command::Command
     pub commands: Vec<CommandId>,
+    /// [`Check`]s run for every command pertaining to this category.
+    ///
+    /// They are run after the [global checks][global], and before the checks of the command.
+    ///
+    /// [`Check`]: crate::check::Check
+    /// [global]: crate::configuration::Configuration::checks
+    pub checks: Vec<Check<D, E>>,
+}
+
+impl<D, E> Clone for Category<D, E> {
+    fn clone(&self) -> Self {
+        Self {
+            name: self.name.clone(),
+            commands: self.commands.clone(),
+            checks: self.checks.clone(),
+        }
+    }
+}
+
+impl<D, E> Default for Category<D, E> {
+    fn default() -> Self {
+        Self {
+            name: String::default(),
+            commands: Vec::default(),
+            checks: Vec::default(),
+        }
+    }
+}
+
+impl<D, E> fmt::Debug for Category<D, E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Category")
+            .field("name", &self.name)
+            .field("commands", &self.commands)
+            .field("checks", &self.checks)
+            .finish()
+    }
 }
@@ -5,6 +5,12 @@
 //! it succeeded or failed. In the case of failure, additional information
 //! can be given, a reason, that describes the failure.
 //!
+//! A command may have many checks. Checks may also be composed into a single
+//! check with the [`all`], [`any`], and [`not`] combinators.
+//!
+//! Subcommands inherit the checks of their parent commands, unless their
+//! [inheritance policy][Inheritance] says otherwise.
+//!
 //! [command]: crate::command
 
 use std::error::Error as StdError;
@@ -13,6 +19,8 @@ use std::fmt::{self, Display};
 use serenity::futures::future::BoxFuture;
 use serenity::model::channel::Message;
 
+use crate::command::Command;
+use crate::configuration::Configuration;
 use crate::context::CheckContext;
 use crate::{DefaultData, DefaultError};
 
@@ -68,6 +76,49 @@ pub type CheckFn<D = DefaultData, E = DefaultError> =
 /// A constructor of the [`Check`] type provided by the consumer of the framework.
 pub type CheckConstructor<D = DefaultData, E = DefaultError> = fn() -> Check<D, E>;
 
+/// The kind of a [`Check`], determining how it is evaluated.
+#[non_exhaustive]
+pub enum CheckKind<D = DefaultData, E = DefaultError> {
+    /// The check is a [function][fn].
+    ///
+    /// [fn]: CheckFn
+    Function(CheckFn<D, E>),
+    /// The check succeeds if all of its checks succeed. Refer to [`all`].
+    All(Vec<Check<D, E>>),
+    /// The check succeeds if any of its checks succeeds. Refer to [`any`].
+    Any(Vec<Check<D, E>>),
+    /// The check succeeds if its check fails. Refer to [`not`].
+    Not(Box<Check<D, E>>),
+}
+
+impl<D, E> Clone for CheckKind<D, E> {
+    fn clone(&self) -> Self {
+        match self {
+            Self::Function(function) => Self::Function(*function),
+            Self::All(checks) => Self::All(checks.clone()),
+            Self::Any(checks) => Self::Any(checks.clone()),
+            Self::Not(check) => Self::Not(check.clone()),
+        }
+    }
+}
+
+impl<D, E> Default for CheckKind<D, E> {
+    fn default() -> Self {
+        Self::Function(|_, _| Box::pin(async move { Ok(()) }))
+    }
+}
+
+impl<D, E> fmt::Debug for CheckKind<D, E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Function(_) => f.debug_tuple("Function").field(&"<fn>").finish(),
+            Self::All(checks) => f.debug_tuple("All").field(checks).finish(),
+            Self::Any(checks) => f.debug_tuple("Any").field(checks).finish(),
+            Self::Not(check) => f.debug_tuple("Not").field(check).finish(),
+        }
+    }
+}
+
 /// Data relating to a check.
 ///
 /// Refer to the [module-level documentation][docs]
@@ -79,8 +130,8 @@ pub struct Check<D = DefaultData, E = DefaultError> {
     ///
     /// Used in help commands.
     pub name: String,
-    /// The function of this check.
-    pub function: CheckFn<D, E>,
+    /// The kind of this check.
+    pub kind: CheckKind<D, E>,
     /// A boolean indicating whether the check can apply in help commands.
     pub check_in_help: bool,
     /// A boolean indicating whether the check can be displayed in help commands.
@@ -91,7 +142,7 @@ impl<D, E> Clone for Check<D, E> {
     fn clone(&self) -> Self {
         Self {
             name: self.name.clone(),
-            function: self.function,
+            kind: self.kind.clone(),
             check_in_help: self.check_in_help,
             display_in_help: self.display_in_help,
         }
@@ -102,7 +153,7 @@ impl<D, E> Default for Check<D, E> {
     fn default() -> Self {
         Self {
             name: String::default(),
-            function: |_, _| Box::pin(async move { Ok(()) }),
+            kind: CheckKind::default(),
             check_in_help: true,
             display_in_help: true,
         }
@@ -113,7 +164,7 @@ impl<D, E> fmt::Debug for Check<D, E> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_struct("Check")
             .field("name", &self.name)
-            .field("function", &"<fn>")
+            .field("kind", &self.kind)
             .field("check_in_help", &self.check_in_help)
             .field("display_in_help", &self.display_in_help)
             .finish()
@@ -130,6 +181,158 @@ impl<D, E> Check<D, E> {
     {
         CheckBuilder::new(name)
     }
+
+    /// Runs this check.
+    ///
+    /// On failure, the name of the check that failed is returned alongside the reason.
+    /// For composite checks, this is the name of the innermost check that failed.
+    /// A failing [`not`] check reports its own name with an [unknown][unk] reason.
+    ///
+    /// [unk]: Reason::Unknown
+    pub fn run<'a>(
+        &'a self,
+        ctx: &'a CheckContext<'_, D, E>,
+        msg: &'a Message,
+    ) -> BoxFuture<'a, Result<(), (&'a str, Reason)>>
+    where
+        D: Send + Sync,
+    {
+        Box::pin(async move {
+            match &self.kind {
+                CheckKind::Function(function) => {
+                    function(ctx, msg).await.map_err(|reason| (self.name.as_str(), reason))
+                },
+                CheckKind::All(checks) => {
+                    for check in checks {
+                        check.run(ctx, msg).await?;
+                    }
+
+                    Ok(())
+                },
+                CheckKind::Any(checks) => {
+                    let mut failure = None;
+
+                    for check in checks {
+                        match check.run(ctx, msg).await {
+                            Ok(()) => return Ok(()),
+                            Err(err) => {
+                                failure.get_or_insert(err);
+                            },
+                        }
+                    }
+
+                    Err(failure.unwrap_or((self.name.as_str(), Reason::Unknown)))
+                },
+                CheckKind::Not(check) => match check.run(ctx, msg).await {
+                    Ok(()) => Err((self.name.as_str(), Reason::Unknown)),
+                    Err(_) => Ok(()),
+                },
+            }
+        })
+    }
+}
+
+/// Composes checks into a check that succeeds if all of them succeed.
+///
+/// The checks are run in order, until one of them fails.
+///
+/// The name of the composite check is the names of the checks joined with "and".
+pub fn all<D, E, I>(checks: I) -> Check<D, E>
+where
+    I: IntoIterator<Item = Check<D, E>>,
+{
+    let checks = checks.into_iter().collect::<Vec<_>>();
+    let mut check = compose(&checks, " and ");
+    check.kind = CheckKind::All(checks);
+    check
+}
+
+/// Composes checks into a check that succeeds if any of them succeeds.
+///
+/// The checks are run in order, until one of them succeeds. If all fail,
+/// the first failure is reported.
+///
+/// The name of the composite check is the names of the checks joined with "or".
+pub fn any<D, E, I>(checks: I) -> Check<D, E>
+where
+    I: IntoIterator<Item = Check<D, E>>,
+{
+    let checks = checks.into_iter().collect::<Vec<_>>();
+    let mut check = compose(&checks, " or ");
+    check.kind = CheckKind::Any(checks);
+    check
+}
+
+/// Inverts a check, making it succeed if the check fails, and vice versa.
+///
+/// The name of the inverted check is the name of the check prefixed with "not".
+pub fn not<D, E>(check: Check<D, E>) -> Check<D, E> {
+    Check {
+        name: format!("not {}", check.name),
+        check_in_help: check.check_in_help,
+        display_in_help: check.display_in_help,
+        kind: CheckKind::Not(Box::new(check)),
+    }
+}
+
+fn compose<D, E>(checks: &[Check<D, E>], separator: &str) -> Check<D, E> {
+    Check {
+        name: checks.iter().map(|c| c.name.as_str()).collect::<Vec<_>>().join(separator),
+        check_in_help: checks.iter().all(|c| c.check_in_help),
+        display_in_help: checks.iter().all(|c| c.display_in_help),
+        ..Check::default()
+    }
+}
+
+/// The policy deciding whether a command inherits the checks of its parent commands.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Inheritance {
+    /// The checks of the parent commands apply to the command, before its own checks.
+    ///
+    /// This is the default policy.
+    #[default]
+    Inherit,
+    /// Only the checks of the command apply, disregarding the checks of its parent commands.
+    ///
+    /// [Global][global] and [category][category] checks still apply.
+    ///
+    /// [global]: crate::configuration::Configuration::checks
+    /// [category]: crate::category::Category::checks
+    Override,
+}
+
+/// Returns the checks applying to the invocation of a command, in order of evaluation.
+///
+/// The invocation is a chain of commands, starting at a root command, followed by
+/// its subcommands. The checks apply to the last command in the chain. They are
+/// the [global] checks, the checks of the [categories] of the root command,
+/// and the checks of the commands in the chain, according to their [`Inheritance`].
+///
+/// [global]: crate::configuration::Configuration::checks
+/// [categories]: crate::category::Category::checks
+pub fn applicable<'a, D, E>(
+    conf: &'a Configuration<D, E>,
+    chain: &[&'a Command<D, E>],
+) -> Vec<&'a Check<D, E>> {
+    let mut checks = conf.checks.iter().collect::<Vec<_>>();
+
+    if let Some(root) = chain.first() {
+        for category in conf.categories.iter().filter(|c| c.commands.contains(&root.id)) {
+            checks.extend(&category.checks);
+        }
+    }
+
+    // Start from the last command that disregards the checks of its parents.
+    let start = chain
+        .iter()
+        .rposition(|cmd| cmd.check_inheritance == Inheritance::Override)
+        .unwrap_or_default();
+
+    for cmd in &chain[start..] {
+        checks.extend(&cmd.checks);
+    }
+
+    checks
 }
 
 /// A builder type for creating a [`Check`] from scratch.
@@ -154,7 +357,7 @@ impl<D, E> CheckBuilder<D, E> {
     }
     /// Assigns the function to this function.
     pub fn function(mut self, function: CheckFn<D, E>) -> Self {
-        self.inner.function = function;
+        self.inner.kind = CheckKind::Function(function);
         self
     }
 
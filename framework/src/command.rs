@@ -14,7 +14,8 @@ use std::fmt;
 use serenity::futures::future::BoxFuture;
 use serenity::model::channel::Message;
 
-use crate::check::{Check, CheckConstructor};
+use crate::argument::Parameter;
+use crate::check::{Check, CheckConstructor, Inheritance};
 use crate::context::Context;
 use crate::utils::IdMap;
 use crate::{DefaultData, DefaultError};
@@ -93,19 +94,44 @@ pub struct Command<D = DefaultData, E = DefaultError> {
     /// A string describing this command.
     pub description: Option<String>,
     /// A function to dynamically describe this command.
-    pub dynamic_description: Option<StringHook>,
+    pub dynamic_description: Option<StringHook<D, E>>,
     /// A string to express usage of this command.
     pub usage: Option<String>,
     /// A function to dynamically express usage of this command.
-    pub dynamic_usage: Option<StringHook>,
+    pub dynamic_usage: Option<StringHook<D, E>>,
     /// A list of strings demonstrating usage of this command.
     pub examples: Vec<String>,
     /// A function to dynamically demonstrate usage of this command.
-    pub dynamic_examples: Option<StringsHook>,
+    pub dynamic_examples: Option<StringsHook<D, E>>,
+    /// The parameters of this command, in order.
+    ///
+    /// These are described by the [`command`] macro, along with their constraints.
+    ///
+    /// [`command`]: crate::prelude::command
+    pub parameters: Vec<Parameter>,
+    /// A boolean indicating whether arguments left over after the parameters
+    /// of this command are an error.
+    ///
+    /// If absent, the [configuration] decides, except for commands without
+    /// parameters, which are then never checked.
+    ///
+    /// [configuration]: crate::configuration::Configuration::strict_arguments
+    pub strict_arguments: Option<bool>,
     /// A boolean to indicate whether the command can be shown in help commands.
     pub help_available: bool,
-    /// A function that allows/denies access to this command.
-    pub check: Option<Check<D, E>>,
+    /// Functions that allow/deny access to this command.
+    ///
+    /// All of them must succeed for the command to be invoked. They are run in order.
+    pub checks: Vec<Check<D, E>>,
+    /// The policy deciding whether this command inherits the checks of its parent commands.
+    pub check_inheritance: Inheritance,
+    /// The name of the [bucket] limiting invocations of this command.
+    ///
+    /// A bucket under this name must exist in the [configuration] when the command is registered.
+    ///
+    /// [bucket]: crate::bucket
+    /// [configuration]: crate::configuration::Configuration::buckets
+    pub bucket: Option<String>,
 }
 
 impl<D, E> Clone for Command<D, E> {
@@ -121,8 +147,12 @@ impl<D, E> Clone for Command<D, E> {
             dynamic_usage: self.dynamic_usage,
             examples: self.examples.clone(),
             dynamic_examples: self.dynamic_examples,
+            parameters: self.parameters.clone(),
+            strict_arguments: self.strict_arguments,
             help_available: self.help_available,
-            check: self.check.clone(),
+            checks: self.checks.clone(),
+            check_inheritance: self.check_inheritance,
+            bucket: self.bucket.clone(),
         }
     }
 }
@@ -140,8 +170,12 @@ impl<D, E> Default for Command<D, E> {
             dynamic_usage: None,
             examples: Vec::default(),
             dynamic_examples: None,
+            parameters: Vec::default(),
+            strict_arguments: None,
             help_available: true,
-            check: None,
+            checks: Vec::default(),
+            check_inheritance: Inheritance::default(),
+            bucket: None,
         }
     }
 }
@@ -159,8 +193,12 @@ impl<D, E> fmt::Debug for Command<D, E> {
             .field("dynamic_usage", &"<fn>")
             .field("examples", &self.examples)
             .field("dynamic_examples", &"<fn>")
+            .field("parameters", &self.parameters)
+            .field("strict_arguments", &self.strict_arguments)
             .field("help_available", &self.help_available)
-            .field("check", &self.check)
+            .field("checks", &self.checks)
+            .field("check_inheritance", &self.check_inheritance)
+            .field("bucket", &self.bucket)
             .finish()
     }
 }
@@ -233,7 +271,7 @@ impl<D, E> CommandBuilder<D, E> {
     }
 
     /// Assigns a function to dynamically create a description to this command.
-    pub fn dynamic_description(mut self, hook: StringHook) -> Self {
+    pub fn dynamic_description(mut self, hook: StringHook<D, E>) -> Self {
         self.inner.dynamic_description = Some(hook);
         self
     }
@@ -248,7 +286,7 @@ impl<D, E> CommandBuilder<D, E> {
     }
 
     /// Assigns a function to dynamically create a usage to this command.
-    pub fn dynamic_usage(mut self, hook: StringHook) -> Self {
+    pub fn dynamic_usage(mut self, hook: StringHook<D, E>) -> Self {
         self.inner.dynamic_usage = Some(hook);
         self
     }
@@ -267,16 +305,63 @@ impl<D, E> CommandBuilder<D, E> {
     }
 
     /// Assigns a function to dynamically create a list of examples to this command.
-    pub fn dynamic_examples(mut self, hook: StringsHook) -> Self {
+    pub fn dynamic_examples(mut self, hook: StringsHook<D, E>) -> Self {
         self.inner.dynamic_examples = Some(hook);
         self
     }
 
+    /// Assigns a parameter to this command.
+    ///
+    /// The parameter is added to the [`parameters`] list.
+    ///
+    /// [`parameters`]: Command::parameters
+    pub fn parameter(mut self, parameter: Parameter) -> Self {
+        self.inner.parameters.push(parameter);
+        self
+    }
+
+    /// Assigns a boolean indicating whether arguments left over after the
+    /// parameters of this command are an error.
+    ///
+    /// This overrides the [configuration].
+    ///
+    /// [configuration]: crate::configuration::Configuration::strict_arguments
+    pub fn strict_arguments(mut self, b: bool) -> Self {
+        self.inner.strict_arguments = Some(b);
+        self
+    }
+
+    /// Assigns a boolean indicating whether this command can be shown in help commands.
+    pub fn help_available(mut self, b: bool) -> Self {
+        self.inner.help_available = b;
+        self
+    }
+
     /// Assigns a [`check`] function to this command.
     ///
+    /// This can be called multiple times to add several checks.
+    ///
     /// [`check`]: crate::check
     pub fn check(mut self, check: CheckConstructor<D, E>) -> Self {
-        self.inner.check = Some(check());
+        self.inner.checks.push(check());
+        self
+    }
+
+    /// Assigns the policy deciding whether this command inherits the checks
+    /// of its parent commands.
+    pub fn check_inheritance(mut self, inheritance: Inheritance) -> Self {
+        self.inner.check_inheritance = inheritance;
+        self
+    }
+
+    /// Assigns the name of a [bucket] to this command.
+    ///
+    /// [bucket]: crate::bucket
+    pub fn bucket<I>(mut self, name: I) -> Self
+    where
+        I: Into<String>,
+    {
+        self.inner.bucket = Some(name.into());
         self
     }
 
@@ -1,21 +1,63 @@
 //! Configuration of the framework.
 
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 use std::fmt;
 
 use serenity::futures::future::BoxFuture;
 use serenity::model::channel::Message;
 use serenity::model::id::UserId;
 
+use crate::bucket::Bucket;
 use crate::category::Category;
-use crate::command::{CommandConstructor, CommandId, CommandMap};
-use crate::context::PrefixContext;
+use crate::check::{Check, CheckConstructor};
+use crate::command::{CommandConstructor, CommandId, CommandMap, CommandResult};
+use crate::context::{Context, ErrorContext, PrefixContext};
+use crate::error::DispatchError;
+use crate::help::HelpConfiguration;
+use crate::utils::DEFAULT_QUOTES;
 use crate::{DefaultData, DefaultError};
 
 /// The definition of the dynamic prefix hook.
 pub type DynamicPrefix<D, E> =
     for<'a> fn(ctx: PrefixContext<'_, D, E>, msg: &'a Message) -> BoxFuture<'a, Option<usize>>;
 
+/// The definition of the before hook.
+///
+/// It is invoked before the command. It returns a boolean indicating whether
+/// the command should be invoked.
+pub type BeforeHook<D, E> =
+    for<'fut> fn(ctx: &'fut Context<D, E>, msg: &'fut Message) -> BoxFuture<'fut, bool>;
+
+/// The definition of the after hook.
+///
+/// It is invoked after the command, receiving its result.
+pub type AfterHook<D, E> = for<'fut> fn(
+    ctx: &'fut Context<D, E>,
+    msg: &'fut Message,
+    result: &'fut CommandResult<(), E>,
+) -> BoxFuture<'fut, ()>;
+
+/// The definition of the dispatch error hook.
+///
+/// It is invoked when dispatching a command fails, unless the message
+/// is a [normal message][normal].
+///
+/// [normal]: crate::error::DispatchError::NormalMessage
+pub type DispatchErrorHook<D, E> = for<'fut> fn(
+    ctx: &'fut ErrorContext<'_, D, E>,
+    msg: &'fut Message,
+    error: &'fut DispatchError,
+) -> BoxFuture<'fut, ()>;
+
+/// The definition of the command error hook.
+///
+/// It is invoked when a command returns an error.
+pub type CommandErrorHook<D, E> = for<'fut> fn(
+    ctx: &'fut Context<D, E>,
+    msg: &'fut Message,
+    error: &'fut E,
+) -> BoxFuture<'fut, ()>;
+
 /// The configuration of the framework.
 #[non_exhaustive]
 pub struct Configuration<D = DefaultData, E = DefaultError> {
@@ -32,10 +74,24 @@ pub struct Configuration<D = DefaultData, E = DefaultError> {
     ///
     /// If filled, this allows for invoking commands by mentioning the bot.
     pub on_mention: Option<String>,
+    /// A list of quotation marks that group arguments, as pairs of opening
+    /// and closing marks.
+    ///
+    /// Defaults to [`DEFAULT_QUOTES`].
+    ///
+    /// [`DEFAULT_QUOTES`]: crate::utils::DEFAULT_QUOTES
+    pub quotes: Vec<(char, char)>,
+    /// A boolean indicating whether arguments left over after the parameters
+    /// of a command are an error.
+    ///
+    /// This is overridden by [`Command::strict_arguments`].
+    ///
+    /// [`Command::strict_arguments`]: crate::command::Command::strict_arguments
+    pub strict_arguments: bool,
     /// A list of [`Category`]s.
     ///
     /// [`Category`]: crate::category::Category
-    pub categories: Vec<Category>,
+    pub categories: Vec<Category<D, E>>,
     /// A set of commands that can only appear at the beginning of a command invocation.
     pub root_level_commands: HashSet<CommandId>,
     /// An [`IdMap`] containing all [`Command`]s.
@@ -43,6 +99,31 @@ pub struct Configuration<D = DefaultData, E = DefaultError> {
     /// [`IdMap`]: crate::utils::IdMap
     /// [`Command`]: crate::command::Command
     pub commands: CommandMap<D, E>,
+    /// The configuration of the [help command][help].
+    ///
+    /// [help]: crate::help
+    pub help: HelpConfiguration,
+    /// A list of [`Check`]s run for every command.
+    ///
+    /// They are run before the checks of [categories] and commands.
+    ///
+    /// [`Check`]: crate::check::Check
+    /// [categories]: crate::category::Category::checks
+    pub checks: Vec<Check<D, E>>,
+    /// A map of [`Bucket`]s, identified by their name.
+    ///
+    /// [`Bucket`]: crate::bucket::Bucket
+    pub buckets: HashMap<String, Bucket>,
+    /// A function invoked before every command.
+    ///
+    /// If it returns `false`, the command is not invoked.
+    pub before: Option<BeforeHook<D, E>>,
+    /// A function invoked after every command.
+    pub after: Option<AfterHook<D, E>>,
+    /// A function invoked when dispatching a command fails.
+    pub on_dispatch_error: Option<DispatchErrorHook<D, E>>,
+    /// A function invoked when a command returns an error.
+    pub on_command_error: Option<CommandErrorHook<D, E>>,
 }
 
 impl<D, E> Clone for Configuration<D, E> {
@@ -53,9 +134,18 @@ impl<D, E> Clone for Configuration<D, E> {
             case_insensitive: self.case_insensitive,
             no_dm_prefix: self.no_dm_prefix,
             on_mention: self.on_mention.clone(),
+            quotes: self.quotes.clone(),
+            strict_arguments: self.strict_arguments,
             categories: self.categories.clone(),
             root_level_commands: self.root_level_commands.clone(),
             commands: self.commands.clone(),
+            help: self.help.clone(),
+            checks: self.checks.clone(),
+            buckets: self.buckets.clone(),
+            before: self.before,
+            after: self.after,
+            on_dispatch_error: self.on_dispatch_error,
+            on_command_error: self.on_command_error,
         }
     }
 }
@@ -68,9 +158,18 @@ impl<D, E> Default for Configuration<D, E> {
             case_insensitive: false,
             no_dm_prefix: false,
             on_mention: None,
+            quotes: DEFAULT_QUOTES.to_vec(),
+            strict_arguments: false,
             categories: Vec::default(),
             root_level_commands: HashSet::default(),
             commands: CommandMap::default(),
+            help: HelpConfiguration::default(),
+            checks: Vec::default(),
+            buckets: HashMap::default(),
+            before: None,
+            after: None,
+            on_dispatch_error: None,
+            on_command_error: None,
         }
     }
 }
@@ -124,15 +223,156 @@ impl<D, E> Configuration<D, E> {
         self
     }
 
+    /// Assigns the quotation marks that group arguments, as pairs of opening
+    /// and closing marks.
+    ///
+    /// This replaces the [default quotation marks][DEFAULT_QUOTES].
+    pub fn quotes<I>(&mut self, quotes: I) -> &mut Self
+    where
+        I: IntoIterator<Item = (char, char)>,
+    {
+        self.quotes = quotes.into_iter().collect();
+        self
+    }
+
+    /// Assigns a boolean indicating whether arguments left over after the
+    /// parameters of a command are an error.
+    ///
+    /// Refer to [`check_extraneous`]. Commands without parameters are only checked
+    /// if [strict mode is enabled for them][cmd], as the check requires their error
+    /// type to be convertible from [`ArgumentError`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::fmt;
+    ///
+    /// use serenity::model::channel::Message;
+    /// use serenity_framework::prelude::*;
+    ///
+    /// #[command]
+    /// async fn kick(_ctx: FrameworkContext, _msg: &Message, user: String) -> CommandResult {
+    ///     assert_eq!(user, "@user");
+    ///     Ok(())
+    /// }
+    ///
+    /// #[command]
+    /// #[strict_arguments(false)]
+    /// async fn say(_ctx: FrameworkContext, _msg: &Message, word: String) -> CommandResult {
+    ///     assert_eq!(word, "hello");
+    ///     Ok(())
+    /// }
+    ///
+    /// // An error type that cannot be converted from argument errors.
+    /// #[derive(Debug)]
+    /// struct StatusError;
+    ///
+    /// impl fmt::Display for StatusError {
+    ///     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    ///         f.write_str("status unavailable")
+    ///     }
+    /// }
+    ///
+    /// #[command]
+    /// async fn status(
+    ///     _ctx: FrameworkContext<(), StatusError>,
+    ///     _msg: &Message,
+    /// ) -> Result<(), StatusError> {
+    ///     Ok(())
+    /// }
+    ///
+    /// # async fn dispatch<D: Send + Sync, E: std::fmt::Display>(
+    /// #     framework: &Framework<D, E>,
+    /// #     content: &str,
+    /// # ) -> Result<(), String> {
+    /// #     use std::sync::Arc;
+    /// #     use serenity::prelude::{RwLock, TypeMap};
+    /// #     let ctx = serenity::client::Context {
+    /// #         data: Arc::new(RwLock::new(TypeMap::new())),
+    /// #         shard: serenity::client::bridge::gateway::ShardMessenger::new(
+    /// #             serenity::futures::channel::mpsc::unbounded().0,
+    /// #         ),
+    /// #         shard_id: 0,
+    /// #         http: Arc::new(serenity::http::Http::new_with_token("")),
+    /// #         cache: Arc::new(serenity::cache::Cache::new()),
+    /// #     };
+    /// #     let msg = serde_json::from_value(serde_json::json!({
+    /// #         "id": "1", "channel_id": "1", "type": 0, "content": content,
+    /// #         "author": { "id": "1", "username": "user", "discriminator": "0001", "avatar": null },
+    /// #         "attachments": [], "embeds": [], "mentions": [], "mention_roles": [],
+    /// #         "mention_everyone": false, "pinned": false, "tts": false,
+    /// #         "timestamp": "2021-01-01T00:00:00Z",
+    /// #     }));
+    /// #     framework.dispatch(&ctx, &msg.unwrap()).await.map_err(|e| e.to_string())
+    /// # }
+    /// # #[tokio::main(flavor = "current_thread")]
+    /// # async fn main() {
+    /// let mut conf: Configuration = Configuration::new();
+    /// conf.prefix("!").strict_arguments(true).command(kick).command(say);
+    /// let framework: Framework = Framework::new(conf);
+    ///
+    /// // `dispatch` invokes the framework with a message, returning its error as text.
+    /// assert_eq!(dispatch(&framework, "!kick @user").await, Ok(()));
+    ///
+    /// let err = dispatch(&framework, "!kick @user now").await.unwrap_err();
+    /// assert_eq!(err, "`now`: too many arguments");
+    ///
+    /// assert_eq!(dispatch(&framework, "!say hello world").await, Ok(()));
+    ///
+    /// let mut conf: Configuration<(), StatusError> = Configuration::new();
+    /// conf.prefix("!").strict_arguments(true).command(status);
+    /// let framework = Framework::new(conf);
+    ///
+    /// // `status` has no parameters and does not enable strict mode.
+    /// assert_eq!(dispatch(&framework, "!status please").await, Ok(()));
+    /// # }
+    /// ```
+    ///
+    /// [`check_extraneous`]: crate::argument::check_extraneous
+    /// [cmd]: crate::command::Command::strict_arguments
+    /// [`ArgumentError`]: crate::argument::ArgumentError
+    pub fn strict_arguments(&mut self, b: bool) -> &mut Self {
+        self.strict_arguments = b;
+        self
+    }
+
     /// Assigns a category to this configuration.
     ///
     /// The category is added to the [`categories`] list. Additionally,
     /// all of its commands [are added][cmd] to the [`commands`] map
     ///
+    /// # Panics
+    ///
+    /// Refer to [`command`][cmd].
+    ///
     /// [`categories`]: Self::categories
     /// [`commands`]: Self::commands
     /// [cmd]: Self::command
     pub fn category<I>(&mut self, name: I, cmds: &[CommandConstructor<D, E>]) -> &mut Self
+    where
+        I: Into<String>,
+    {
+        self.category_with_checks(name, &[], cmds)
+    }
+
+    /// Assigns a category with [`check`]s to this configuration.
+    ///
+    /// The checks are run for every command of the category.
+    /// Otherwise, this is the same as [`category`].
+    ///
+    /// # Panics
+    ///
+    /// Refer to [`command`].
+    ///
+    /// [`check`]: crate::check
+    /// [`category`]: Self::category
+    /// [`command`]: Self::command
+    pub fn category_with_checks<I>(
+        &mut self,
+        name: I,
+        checks: &[CheckConstructor<D, E>],
+        cmds: &[CommandConstructor<D, E>],
+    ) -> &mut Self
     where
         I: Into<String>,
     {
@@ -146,6 +386,7 @@ impl<D, E> Configuration<D, E> {
         self.categories.push(Category {
             name: name.into(),
             commands,
+            checks: checks.iter().map(|check| check()).collect(),
         });
 
         self
@@ -156,8 +397,15 @@ impl<D, E> Configuration<D, E> {
     /// The command is added to the [`commands`] map, alongside its subcommands.
     /// It it also added into the [`root_level_commands`] set.
     ///
+    /// # Panics
+    ///
+    /// This function panics if the command or one of its subcommands refers to
+    /// a [bucket] that is not registered. Buckets must thus be registered before
+    /// the commands using them.
+    ///
     /// [`commands`]: Self::commands
     /// [`root_level_commands`]: Self::root_level_commands
+    /// [bucket]: Self::bucket
     pub fn command(&mut self, command: CommandConstructor<D, E>) -> &mut Self {
         let id = CommandId::from(command);
 
@@ -171,10 +419,81 @@ impl<D, E> Configuration<D, E> {
         self
     }
 
+    /// Assigns the configuration of the [help command][help].
+    ///
+    /// [help]: crate::help
+    pub fn help(&mut self, help: HelpConfiguration) -> &mut Self {
+        self.help = help;
+        self
+    }
+
+    /// Assigns a [`check`] to this configuration, which is run for every command.
+    ///
+    /// [`check`]: crate::check
+    pub fn check(&mut self, check: CheckConstructor<D, E>) -> &mut Self {
+        self.checks.push(check());
+        self
+    }
+
+    /// Assigns a [`Bucket`] to this configuration under a name.
+    ///
+    /// Commands refer to the bucket [by its name][cmd].
+    ///
+    /// [`Bucket`]: crate::bucket::Bucket
+    /// [cmd]: crate::command::Command::bucket
+    pub fn bucket<I>(&mut self, name: I, bucket: Bucket) -> &mut Self
+    where
+        I: Into<String>,
+    {
+        self.buckets.insert(name.into(), bucket);
+        self
+    }
+
+    /// Assigns a function that is invoked before every command.
+    ///
+    /// If the function returns `false`, the command is not invoked.
+    pub fn before(&mut self, hook: BeforeHook<D, E>) -> &mut Self {
+        self.before = Some(hook);
+        self
+    }
+
+    /// Assigns a function that is invoked after every command.
+    ///
+    /// The function receives the result of the command.
+    pub fn after(&mut self, hook: AfterHook<D, E>) -> &mut Self {
+        self.after = Some(hook);
+        self
+    }
+
+    /// Assigns a function that is invoked when dispatching a command fails.
+    ///
+    /// The function is not invoked for [normal messages][normal].
+    ///
+    /// [normal]: crate::error::DispatchError::NormalMessage
+    pub fn on_dispatch_error(&mut self, hook: DispatchErrorHook<D, E>) -> &mut Self {
+        self.on_dispatch_error = Some(hook);
+        self
+    }
+
+    /// Assigns a function that is invoked when a command returns an error.
+    pub fn on_command_error(&mut self, hook: CommandErrorHook<D, E>) -> &mut Self {
+        self.on_command_error = Some(hook);
+        self
+    }
+
     fn _command(&mut self, id: CommandId, command: CommandConstructor<D, E>) {
         let mut command = command();
         command.id = id;
 
+        if let Some(bucket) = &command.bucket {
+            assert!(
+                self.buckets.contains_key(bucket),
+                "the bucket `{}` of the command `{}` is not registered",
+                bucket,
+                command.names[0]
+            );
+        }
+
         for name in &command.names {
             let name = if self.case_insensitive { name.to_lowercase() } else { name.clone() };
 
@@ -203,9 +522,18 @@ impl<D, E> fmt::Debug for Configuration<D, E> {
             .field("case_insensitive", &self.case_insensitive)
             .field("no_dm_prefix", &self.no_dm_prefix)
             .field("on_mention", &self.on_mention)
+            .field("quotes", &self.quotes)
+            .field("strict_arguments", &self.strict_arguments)
             .field("categories", &self.categories)
             .field("root_level_commands", &self.root_level_commands)
             .field("commands", &self.commands)
+            .field("help", &self.help)
+            .field("checks", &self.checks)
+            .field("buckets", &self.buckets)
+            .field("before", &"<fn>")
+            .field("after", &"<fn>")
+            .field("on_dispatch_error", &"<fn>")
+            .field("on_command_error", &"<fn>")
             .finish()
     }
 }
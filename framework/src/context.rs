@@ -189,3 +189,54 @@ where
         Some(&self.serenity_ctx.cache)
     }
 }
+
+/// The error context.
+///
+/// This is passed to the [dispatch error hook][hook].
+///
+/// [hook]: crate::configuration::DispatchErrorHook
+#[non_exhaustive]
+pub struct ErrorContext<'a, D = DefaultData, E = DefaultError> {
+    /// User data.
+    pub data: &'a Arc<D>,
+    /// Framework configuration.
+    pub conf: &'a Configuration<D, E>,
+    /// Serenity's context type.
+    pub serenity_ctx: &'a SerenityContext,
+}
+
+impl<'a, D, E> Clone for ErrorContext<'a, D, E> {
+    fn clone(&self) -> Self {
+        Self {
+            data: self.data,
+            conf: self.conf,
+            serenity_ctx: self.serenity_ctx,
+        }
+    }
+}
+
+impl<D, E> AsRef<Http> for ErrorContext<'_, D, E> {
+    fn as_ref(&self) -> &Http {
+        &self.serenity_ctx.http
+    }
+}
+
+impl<D, E> AsRef<Cache> for ErrorContext<'_, D, E> {
+    fn as_ref(&self) -> &Cache {
+        &self.serenity_ctx.cache
+    }
+}
+
+impl<D, E> CacheHttp for ErrorContext<'_, D, E>
+where
+    D: Send + Sync,
+    E: Send + Sync,
+{
+    fn http(&self) -> &Http {
+        &self.serenity_ctx.http
+    }
+
+    fn cache(&self) -> Option<&Arc<Cache>> {
+        Some(&self.serenity_ctx.cache)
+    }
+}
@@ -2,6 +2,7 @@
 
 use std::error::Error as StdError;
 use std::fmt;
+use std::time::Duration;
 
 use crate::check::Reason;
 use crate::DefaultError;
@@ -16,9 +17,25 @@ pub enum DispatchError {
     /// The message only contains a prefix. Contains the prefix.
     PrefixOnly(String),
     /// The message contains a name not belonging to any command.
-    InvalidCommandName(String),
+    ///
+    /// Contains the name and a list of names of similar commands, ranked by their
+    /// similarity. Refer to [`parse::suggestions`].
+    ///
+    /// [`parse::suggestions`]: crate::parse::suggestions
+    InvalidCommandName(String, Vec<String>),
     /// A check failed. Contains its name and the reasoning why it failed.
+    ///
+    /// For [composite checks][comp], this is the name of the check that failed within it.
+    ///
+    /// [comp]: crate::check::CheckKind
     CheckFailed(String, Reason),
+    /// The command was invoked too often. Refer to [buckets].
+    ///
+    /// [buckets]: crate::bucket
+    RateLimited {
+        /// The time after which the command can be invoked again.
+        retry_after: Duration,
+    },
 }
 
 impl fmt::Display for DispatchError {
@@ -30,10 +47,21 @@ impl fmt::Display for DispatchError {
             DispatchError::PrefixOnly(prefix) => {
                 write!(f, "only the prefix (`{}`) is present", prefix)
             },
-            DispatchError::InvalidCommandName(name) => {
-                write!(f, "name \"{}\" does not refer to any command", name)
+            DispatchError::InvalidCommandName(name, suggestions) => {
+                write!(f, "name \"{}\" does not refer to any command", name)?;
+
+                if !suggestions.is_empty() {
+                    write!(f, "; did you mean \"{}\"?", suggestions.join("\", \""))?;
+                }
+
+                Ok(())
             },
             DispatchError::CheckFailed(name, _) => write!(f, "\"{}\" check failed", name),
+            DispatchError::RateLimited {
+                retry_after,
+            } => {
+                write!(f, "rate limited; retry after {:.2} seconds", retry_after.as_secs_f64())
+            },
         }
     }
 }
@@ -0,0 +1,651 @@
+//! A built-in help command.
+//!
+//! The help command displays information about commands to the user.
+//! When invoked without arguments, it displays an overview of all commands,
+//! grouped by their [categories]. When invoked with the names of a command,
+//! and optionally its subcommands, it displays details about the command,
+//! such as its description, usage, and examples:
+//!
+//! ```text
+//! !help
+//! !help ban
+//! !help config set
+//! ```
+//!
+//! Commands whose [`help_available`] field is `false` are omitted from the help command.
+//! So are commands whose checks fail for the user invoking the help command,
+//! unless the check [does not apply in help][check_in_help]. The names of checks
+//! that [can be displayed in help][display_in_help] are listed next to their command.
+//!
+//! The command is registered like any other command:
+//!
+//! ```rust,no_run
+//! use serenity_framework::help;
+//! use serenity_framework::prelude::*;
+//!
+//! let mut conf: Configuration = Configuration::new();
+//! conf.command(help::help);
+//! ```
+//!
+//! Its behaviour can be adjusted with [`Configuration::help`].
+//!
+//! # Content and presentation
+//!
+//! The content of the help command is separate from its presentation.
+//! The content is gathered into a [`HelpTree`] for the overview, or into a
+//! [`CommandHelp`] for the details of a single command. Both are independent
+//! of Discord and of the framework's configuration. They are then presented
+//! to the user by a [`Renderer`]. The framework provides renderers for
+//! [plain text][PlainText], [text in a code block][CodeBlock], and [embeds][Embed].
+//! A custom renderer may be assigned with [`HelpConfiguration::renderer`].
+//!
+//! [categories]: crate::category
+//! [`help_available`]: crate::command::Command::help_available
+//! [`Configuration::help`]: crate::configuration::Configuration::help
+//! [check_in_help]: crate::check::Check::check_in_help
+//! [display_in_help]: crate::check::Check::display_in_help
+
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::Arc;
+
+use serenity::futures::future::BoxFuture;
+use serenity::model::channel::Message;
+use serenity::Error as SerenityError;
+
+use crate::argument::Parameter;
+use crate::check::{self, CheckKind};
+use crate::command::{Command, CommandId, CommandResult};
+use crate::configuration::Configuration;
+use crate::context::{CheckContext, Context};
+use crate::parse;
+use crate::utils::Segments;
+
+pub mod render;
+
+pub use render::{CodeBlock, Embed, HelpMessage, PlainText, Renderer};
+
+/// The configuration of the [help command][help].
+///
+/// [help]: self
+#[derive(Clone)]
+#[non_exhaustive]
+pub struct HelpConfiguration {
+    /// Text displayed above the overview of commands.
+    pub header: Option<String>,
+    /// Name of the category under which commands without a category are listed.
+    pub uncategorized_name: String,
+    /// A boolean indicating whether aliases of a command are displayed.
+    pub show_aliases: bool,
+    /// The [`Renderer`] that presents the help content to the user.
+    ///
+    /// Defaults to [`PlainText`].
+    pub renderer: Arc<dyn Renderer>,
+}
+
+impl Default for HelpConfiguration {
+    fn default() -> Self {
+        Self {
+            header: None,
+            uncategorized_name: "Uncategorized".to_string(),
+            show_aliases: true,
+            renderer: Arc::new(PlainText),
+        }
+    }
+}
+
+impl HelpConfiguration {
+    /// Creates a new instance of the help configuration.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Assigns the text displayed above the overview of commands.
+    pub fn header<I>(&mut self, header: I) -> &mut Self
+    where
+        I: Into<String>,
+    {
+        self.header = Some(header.into());
+        self
+    }
+
+    /// Assigns the name of the category for commands without a category.
+    pub fn uncategorized_name<I>(&mut self, name: I) -> &mut Self
+    where
+        I: Into<String>,
+    {
+        self.uncategorized_name = name.into();
+        self
+    }
+
+    /// Assigns a boolean indicating whether aliases of a command are displayed.
+    pub fn show_aliases(&mut self, b: bool) -> &mut Self {
+        self.show_aliases = b;
+        self
+    }
+
+    /// Assigns the [`Renderer`] that presents the help content to the user.
+    pub fn renderer<R>(&mut self, renderer: R) -> &mut Self
+    where
+        R: Renderer + 'static,
+    {
+        self.renderer = Arc::new(renderer);
+        self
+    }
+}
+
+impl fmt::Debug for HelpConfiguration {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("HelpConfiguration")
+            .field("header", &self.header)
+            .field("uncategorized_name", &self.uncategorized_name)
+            .field("show_aliases", &self.show_aliases)
+            .field("renderer", &"<renderer>")
+            .finish()
+    }
+}
+
+/// Help content of all commands.
+///
+/// This is displayed when the help command is invoked without arguments.
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub struct HelpTree {
+    /// The prefix that was used to invoke the help command.
+    pub prefix: String,
+    /// The categories of commands.
+    ///
+    /// Commands that do not belong to any category are placed in the last
+    /// category, named after [`HelpConfiguration::uncategorized_name`].
+    /// Categories without commands are omitted.
+    pub categories: Vec<CategoryHelp>,
+}
+
+/// Help content of a [`Category`].
+///
+/// [`Category`]: crate::category::Category
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub struct CategoryHelp {
+    /// Name of the category.
+    pub name: String,
+    /// Commands pertaining to this category.
+    pub commands: Vec<CommandHelp>,
+}
+
+/// Help content of a [`Command`].
+///
+/// [`Command`]: crate::command::Command
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub struct CommandHelp {
+    /// The identifier of the command.
+    pub id: CommandId,
+    /// The names of the command.
+    ///
+    /// The first name is the main name; the rest are aliases.
+    pub names: Vec<String>,
+    /// The main names of the command's parent commands and of the command itself,
+    /// separated by spaces. For example, `config set`.
+    pub path: String,
+    /// The description of the command.
+    ///
+    /// This is the result of the [dynamic description][dyn] hook if it returned
+    /// a description, otherwise the static description.
+    ///
+    /// [dyn]: crate::command::Command::dynamic_description
+    pub description: Option<String>,
+    /// The usage of the command.
+    ///
+    /// This is the result of the [dynamic usage][dyn] hook if it returned
+    /// a usage, otherwise the static usage.
+    ///
+    /// [dyn]: crate::command::Command::dynamic_usage
+    pub usage: Option<String>,
+    /// The static examples of the command, followed by the examples of the
+    /// [dynamic examples][dyn] hook.
+    ///
+    /// [dyn]: crate::command::Command::dynamic_examples
+    pub examples: Vec<String>,
+    /// The parameters of the command, along with their constraints and choices.
+    pub parameters: Vec<Parameter>,
+    /// The names of the checks applying to the command that can be displayed in help.
+    ///
+    /// These are the [applicable checks][applicable], including inherited checks,
+    /// that [can be displayed][display].
+    ///
+    /// [applicable]: crate::check::applicable
+    /// [display]: crate::check::Check::display_in_help
+    pub checks: Vec<String>,
+    /// The subcommands of the command, sorted by their main name.
+    pub subcommands: Vec<CommandHelp>,
+}
+
+impl CommandHelp {
+    /// Returns the main name of the command.
+    pub fn name(&self) -> &str {
+        &self.names[0]
+    }
+
+    /// Returns the aliases of the command.
+    pub fn aliases(&self) -> &[String] {
+        &self.names[1..]
+    }
+}
+
+impl HelpTree {
+    /// Gathers the help content of all commands.
+    ///
+    /// Commands that are [not available in help][help], or whose checks
+    /// [applying in help][check] fail for the author of the message, are omitted.
+    ///
+    /// [help]: crate::command::Command::help_available
+    /// [check]: crate::check::Check::check_in_help
+    pub async fn new<D, E>(ctx: &Context<D, E>, msg: &Message) -> Self
+    where
+        D: Send + Sync,
+    {
+        // Copy the commands out of the configuration, as the dynamic hooks
+        // may need to lock the configuration themselves.
+        let (categories, commands, requirements) = {
+            let conf = ctx.conf.lock().await;
+
+            let mut collector = Collector::new(ctx, msg, &conf);
+            let mut categories = Vec::with_capacity(conf.categories.len() + 1);
+
+            for category in &conf.categories {
+                let mut ids = Vec::with_capacity(category.commands.len());
+
+                for id in &category.commands {
+                    if collector.available(&[*id]).await {
+                        collector.collect(vec![*id]).await;
+                        ids.push(*id);
+                    }
+                }
+
+                categories.push((category.name.clone(), ids));
+            }
+
+            let mut uncategorized = Vec::new();
+
+            for id in &conf.root_level_commands {
+                if conf.categories.iter().any(|c| c.commands.contains(id)) {
+                    continue;
+                }
+
+                if collector.available(&[*id]).await {
+                    collector.collect(vec![*id]).await;
+                    uncategorized.push(*id);
+                }
+            }
+
+            let Collector {
+                commands,
+                requirements,
+                ..
+            } = collector;
+
+            uncategorized.sort_by(|a, b| commands[a].names.cmp(&commands[b].names));
+
+            categories.push((conf.help.uncategorized_name.clone(), uncategorized));
+
+            (categories, commands, requirements)
+        };
+
+        let helps = resolve(ctx, msg, &commands, &requirements).await;
+
+        let categories = categories
+            .into_iter()
+            .filter(|(_, ids)| !ids.is_empty())
+            .map(|(name, ids)| CategoryHelp {
+                name,
+                commands: ids
+                    .into_iter()
+                    .filter_map(|id| assemble(id, "", &helps, &mut Vec::new()))
+                    .collect(),
+            })
+            .collect();
+
+        Self {
+            prefix: ctx.prefix.clone(),
+            categories,
+        }
+    }
+}
+
+impl CommandHelp {
+    /// Gathers the help content of a command, identified by its name, followed
+    /// by the names of its subcommands. For example, `config set`.
+    ///
+    /// If the names do not refer to a command that is [available in help][help],
+    /// or the checks [applying in help][check] to the command fail for the author
+    /// of the message, `None` is returned. The checks are subject to [inheritance].
+    ///
+    /// [help]: crate::command::Command::help_available
+    /// [check]: crate::check::Check::check_in_help
+    /// [inheritance]: crate::check::Inheritance
+    pub async fn new<D, E>(ctx: &Context<D, E>, msg: &Message, names: &str) -> Option<Self>
+    where
+        D: Send + Sync,
+    {
+        let (id, parent_path, commands, requirements) = {
+            let conf = ctx.conf.lock().await;
+
+            let mut segments = Segments::new(names, " ", conf.case_insensitive);
+
+            let mut path = Vec::new();
+
+            for cmd in parse::commands(&conf, &mut segments) {
+                path.push(cmd.ok()?);
+            }
+
+            // Leftover names do not refer to any subcommand.
+            if !segments.is_empty() {
+                return None;
+            }
+
+            let mut collector = Collector::new(ctx, msg, &conf);
+
+            let chain = path.iter().map(|cmd| cmd.id).collect::<Vec<_>>();
+
+            if !collector.available(&chain).await {
+                return None;
+            }
+
+            let command = path.pop()?;
+            let parent_path =
+                path.iter().map(|cmd| cmd.names[0].as_str()).collect::<Vec<_>>().join(" ");
+
+            collector.collect(chain).await;
+
+            (command.id, parent_path, collector.commands, collector.requirements)
+        };
+
+        let helps = resolve(ctx, msg, &commands, &requirements).await;
+
+        assemble(id, &parent_path, &helps, &mut Vec::new())
+    }
+}
+
+/// Copies commands available to the author of a message out of the configuration.
+struct Collector<'a, D, E> {
+    ctx: &'a Context<D, E>,
+    msg: &'a Message,
+    conf: &'a Configuration<D, E>,
+    /// Results of checks, identified by their function and the command they were
+    /// run for.
+    ///
+    /// Checks may be inherited by many subcommands. They are only run once per
+    /// command and invocation of the help command. Results are not shared between
+    /// commands, as checks may depend on the command being checked.
+    checks: HashMap<(usize, CommandId), bool>,
+    commands: HashMap<CommandId, Command<D, E>>,
+    /// Names of the applicable checks of the copied commands that can be displayed
+    /// in help.
+    requirements: HashMap<CommandId, Vec<String>>,
+}
+
+impl<'a, D, E> Collector<'a, D, E>
+where
+    D: Send + Sync,
+{
+    fn new(ctx: &'a Context<D, E>, msg: &'a Message, conf: &'a Configuration<D, E>) -> Self {
+        Self {
+            ctx,
+            msg,
+            conf,
+            checks: HashMap::new(),
+            commands: HashMap::new(),
+            requirements: HashMap::new(),
+        }
+    }
+
+    /// Returns a boolean indicating whether the last command in a chain of commands
+    /// is available in help.
+    ///
+    /// The chain starts at a root command, followed by its subcommands. All of them
+    /// must be available in help, and the [applicable checks][check] must succeed.
+    ///
+    /// [check]: crate::check::applicable
+    async fn available(&mut self, chain: &[CommandId]) -> bool {
+        let conf = self.conf;
+
+        let mut commands = Vec::with_capacity(chain.len());
+
+        for id in chain {
+            match conf.commands.get(*id) {
+                Some(cmd) if cmd.help_available => commands.push(cmd),
+                _ => return false,
+            }
+        }
+
+        let id = match chain.last() {
+            Some(id) => *id,
+            None => return false,
+        };
+
+        let ctx = CheckContext {
+            data: &self.ctx.data,
+            conf,
+            serenity_ctx: &self.ctx.serenity_ctx,
+            command_id: id,
+        };
+
+        for check in check::applicable(conf, &commands) {
+            if !check.check_in_help {
+                continue;
+            }
+
+            // Only the results of function checks are cached, as composite
+            // checks have no identity.
+            let key = match check.kind {
+                CheckKind::Function(function) => Some((function as usize, id)),
+                _ => None,
+            };
+
+            if let Some(result) = key.and_then(|key| self.checks.get(&key)) {
+                if !result {
+                    return false;
+                }
+
+                continue;
+            }
+
+            let result = check.run(&ctx, self.msg).await.is_ok();
+
+            if let Some(key) = key {
+                self.checks.insert(key, result);
+            }
+
+            if !result {
+                return false;
+            }
+        }
+
+        true
+    }
+
+    /// Copies the last command in a chain of commands and its available subcommands.
+    ///
+    /// The availability of the command itself must be determined beforehand.
+    async fn collect(&mut self, chain: Vec<CommandId>) {
+        let conf = self.conf;
+        let mut stack = vec![chain];
+
+        while let Some(chain) = stack.pop() {
+            let id = match chain.last() {
+                Some(id) => *id,
+                None => continue,
+            };
+
+            if self.commands.contains_key(&id) {
+                continue;
+            }
+
+            let command = &conf.commands[id];
+
+            for subcommand in &command.subcommands {
+                let mut chain = chain.clone();
+                chain.push(*subcommand);
+
+                if self.available(&chain).await {
+                    stack.push(chain);
+                }
+            }
+
+            let chain = chain.iter().map(|id| &conf.commands[*id]).collect::<Vec<_>>();
+
+            let mut names = Vec::<String>::new();
+
+            for check in check::applicable(conf, &chain) {
+                if check.display_in_help && !names.contains(&check.name) {
+                    names.push(check.name.clone());
+                }
+            }
+
+            self.requirements.insert(id, names);
+            self.commands.insert(id, command.clone());
+        }
+    }
+}
+
+/// Gathers the help content of every command, excluding their subcommands.
+async fn resolve<D, E>(
+    ctx: &Context<D, E>,
+    msg: &Message,
+    commands: &HashMap<CommandId, Command<D, E>>,
+    requirements: &HashMap<CommandId, Vec<String>>,
+) -> HashMap<CommandId, (CommandHelp, Vec<CommandId>)> {
+    let mut helps = HashMap::with_capacity(commands.len());
+
+    for (id, command) in commands {
+        let mut ctx = ctx.clone();
+        ctx.command_id = *id;
+
+        let mut description = command.description.clone();
+
+        if let Some(hook) = command.dynamic_description {
+            if let Some(desc) = hook(&ctx, msg).await {
+                description = Some(desc);
+            }
+        }
+
+        let mut usage = command.usage.clone();
+
+        if let Some(hook) = command.dynamic_usage {
+            if let Some(u) = hook(&ctx, msg).await {
+                usage = Some(u);
+            }
+        }
+
+        let mut examples = command.examples.clone();
+
+        if let Some(hook) = command.dynamic_examples {
+            examples.extend(hook(&ctx, msg).await);
+        }
+
+        let checks = requirements.get(id).cloned().unwrap_or_default();
+
+        let subcommands =
+            command.subcommands.iter().copied().filter(|id| commands.contains_key(id)).collect();
+
+        let help = CommandHelp {
+            id: *id,
+            names: command.names.clone(),
+            path: String::new(),
+            description,
+            usage,
+            examples,
+            parameters: command.parameters.clone(),
+            checks,
+            subcommands: Vec::new(),
+        };
+
+        helps.insert(*id, (help, subcommands));
+    }
+
+    helps
+}
+
+/// Assembles the tree of a command and its subcommands.
+fn assemble(
+    id: CommandId,
+    parent_path: &str,
+    helps: &HashMap<CommandId, (CommandHelp, Vec<CommandId>)>,
+    ancestors: &mut Vec<CommandId>,
+) -> Option<CommandHelp> {
+    // Guard against commands that are their own (indirect) subcommands.
+    if ancestors.contains(&id) {
+        return None;
+    }
+
+    let (help, subcommands) = helps.get(&id)?;
+    let mut help = help.clone();
+
+    help.path = if parent_path.is_empty() {
+        help.names[0].clone()
+    } else {
+        format!("{} {}", parent_path, help.names[0])
+    };
+
+    ancestors.push(id);
+
+    help.subcommands =
+        subcommands.iter().filter_map(|id| assemble(*id, &help.path, helps, ancestors)).collect();
+    help.subcommands.sort_by(|a, b| a.names[0].cmp(&b.names[0]));
+
+    ancestors.pop();
+
+    Some(help)
+}
+
+/// Constructs the help command.
+///
+/// Refer to the [module-level documentation][docs].
+///
+/// [docs]: self
+pub fn help<D, E>() -> Command<D, E>
+where
+    D: Send + Sync + 'static,
+    E: From<SerenityError> + 'static,
+{
+    Command::builder("help")
+        .function(help_command)
+        .description("Displays information about commands.")
+        .usage("[command] [subcommand...]")
+        .build()
+}
+
+fn help_command<D, E>(ctx: Context<D, E>, msg: &Message) -> BoxFuture<'_, CommandResult<(), E>>
+where
+    D: Send + Sync + 'static,
+    E: From<SerenityError> + 'static,
+{
+    Box::pin(async move {
+        let help_conf = ctx.conf.lock().await.help.clone();
+        let renderer = &help_conf.renderer;
+
+        let messages = if ctx.args.is_empty() {
+            let tree = HelpTree::new(&ctx, msg).await;
+            renderer.overview(&help_conf, &tree)
+        } else {
+            match CommandHelp::new(&ctx, msg, &ctx.args).await {
+                Some(command) => renderer.command(&help_conf, &ctx.prefix, &command),
+                None => renderer.not_found(&help_conf, &ctx.args),
+            }
+        };
+
+        for message in messages {
+            match message {
+                HelpMessage::Text(content) => {
+                    msg.channel_id.say(&ctx, content).await?;
+                },
+                HelpMessage::Embed(embed) => {
+                    msg.channel_id.send_message(&ctx, |m| m.set_embed(embed)).await?;
+                },
+            }
+        }
+
+        Ok(())
+    })
+}
@@ -0,0 +1,538 @@
+//! Presentation of the help content.
+//!
+//! Refer to the [`Renderer`] trait.
+//!
+//! Discord limits the length of messages and embeds. The renderers of the framework
+//! split content exceeding these limits into several messages, and fields of embeds
+//! into several fields. Custom renderers may do so with [`split`].
+
+use std::fmt::Write;
+
+use serenity::builder::CreateEmbed;
+use serenity::utils::Colour;
+
+use super::{CommandHelp, HelpConfiguration, HelpTree};
+
+/// A message produced by a [`Renderer`].
+#[derive(Debug, Clone)]
+pub enum HelpMessage {
+    /// A message with text content.
+    Text(String),
+    /// A message with an embed.
+    Embed(CreateEmbed),
+}
+
+/// The maximum length of the content of a message, in characters.
+pub const MESSAGE_LENGTH: usize = 2000;
+
+/// The maximum length of the description of an embed, in characters.
+pub const EMBED_DESCRIPTION_LENGTH: usize = 4096;
+
+/// The maximum length of the name of an embed field, in characters.
+pub const FIELD_NAME_LENGTH: usize = 256;
+
+/// The maximum length of the value of an embed field, in characters.
+pub const FIELD_VALUE_LENGTH: usize = 1024;
+
+/// The maximum amount of fields in an embed.
+pub const EMBED_FIELDS: usize = 25;
+
+/// The maximum length of all text in an embed, in characters.
+pub const EMBED_LENGTH: usize = 6000;
+
+/// A type that presents the help content to the user.
+///
+/// Every method returns a list of messages, which are sent in order.
+/// Each message must stay within the limits of Discord.
+pub trait Renderer: Send + Sync {
+    /// Renders an overview of all commands.
+    fn overview(&self, conf: &HelpConfiguration, tree: &HelpTree) -> Vec<HelpMessage>;
+
+    /// Renders the details of a command.
+    ///
+    /// The prefix is the prefix that was used to invoke the help command.
+    fn command(
+        &self,
+        conf: &HelpConfiguration,
+        prefix: &str,
+        command: &CommandHelp,
+    ) -> Vec<HelpMessage>;
+
+    /// Renders the message displayed when the names given to the help command
+    /// do not refer to any command.
+    fn not_found(&self, conf: &HelpConfiguration, names: &str) -> Vec<HelpMessage> {
+        let _ = conf;
+
+        let content = format!("No command named `{}` was found.", names);
+
+        split(&content, MESSAGE_LENGTH).into_iter().map(HelpMessage::Text).collect()
+    }
+}
+
+/// A [`Renderer`] presenting the help content as text formatted with Markdown.
+///
+/// This is the default renderer.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct PlainText;
+
+impl Renderer for PlainText {
+    fn overview(&self, conf: &HelpConfiguration, tree: &HelpTree) -> Vec<HelpMessage> {
+        let mut content = String::new();
+
+        if let Some(header) = &conf.header {
+            writeln!(content, "{}\n", header).unwrap();
+        }
+
+        for category in &tree.categories {
+            writeln!(content, "**{}**", category.name).unwrap();
+
+            for command in &category.commands {
+                write!(content, "`{}`", command.name()).unwrap();
+
+                if let Some(summary) = summary(command) {
+                    write!(content, " - {}", summary).unwrap();
+                }
+
+                if !command.checks.is_empty() {
+                    write!(content, " *(Requires: {})*", command.checks.join(", ")).unwrap();
+                }
+
+                content.push('\n');
+            }
+
+            content.push('\n');
+        }
+
+        write!(content, "Type `{}help <command>` for more information on a command.", tree.prefix)
+            .unwrap();
+
+        text_messages(&content)
+    }
+
+    fn command(
+        &self,
+        conf: &HelpConfiguration,
+        prefix: &str,
+        command: &CommandHelp,
+    ) -> Vec<HelpMessage> {
+        let mut content = format!("**{}**\n", command.path);
+
+        if conf.show_aliases && !command.aliases().is_empty() {
+            writeln!(content, "Aliases: {}", code_list(command.aliases())).unwrap();
+        }
+
+        if let Some(description) = &command.description {
+            writeln!(content, "{}", description).unwrap();
+        }
+
+        if let Some(usage) = &command.usage {
+            writeln!(content, "\n**Usage:** `{}{} {}`", prefix, command.path, usage).unwrap();
+        }
+
+        if !command.examples.is_empty() {
+            content.push_str("**Examples:**\n");
+
+            for example in &command.examples {
+                writeln!(content, "`{}{} {}`", prefix, command.path, example).unwrap();
+            }
+        }
+
+        if choices(command).next().is_some() {
+            content.push_str("**Choices:**\n");
+
+            for (name, choices) in choices(command) {
+                writeln!(content, "`{}`: {}", name, code_list(choices)).unwrap();
+            }
+        }
+
+        if !command.subcommands.is_empty() {
+            let names = command.subcommands.iter().map(|c| c.name()).collect::<Vec<_>>();
+            writeln!(content, "**Subcommands:** {}", code_list(&names)).unwrap();
+        }
+
+        if !command.checks.is_empty() {
+            writeln!(content, "**Requires:** {}", command.checks.join(", ")).unwrap();
+        }
+
+        text_messages(&content)
+    }
+}
+
+/// A [`Renderer`] presenting the help content as text inside a code block.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct CodeBlock;
+
+impl Renderer for CodeBlock {
+    fn overview(&self, conf: &HelpConfiguration, tree: &HelpTree) -> Vec<HelpMessage> {
+        let width = tree
+            .categories
+            .iter()
+            .flat_map(|c| &c.commands)
+            .map(|c| c.name().chars().count())
+            .max()
+            .unwrap_or_default();
+
+        let mut content = String::new();
+
+        if let Some(header) = &conf.header {
+            writeln!(content, "{}\n", header).unwrap();
+        }
+
+        for category in &tree.categories {
+            writeln!(content, "{}:", category.name).unwrap();
+
+            for command in &category.commands {
+                match summary(command) {
+                    Some(summary) => {
+                        write!(content, "  {:width$}  {}", command.name(), summary, width = width)
+                            .unwrap();
+                    },
+                    None => write!(content, "  {}", command.name()).unwrap(),
+                }
+
+                if !command.checks.is_empty() {
+                    write!(content, " (Requires: {})", command.checks.join(", ")).unwrap();
+                }
+
+                content.push('\n');
+            }
+
+            content.push('\n');
+        }
+
+        write!(content, "Type {}help <command> for more information on a command.", tree.prefix)
+            .unwrap();
+
+        code_block_messages(&content)
+    }
+
+    fn command(
+        &self,
+        conf: &HelpConfiguration,
+        prefix: &str,
+        command: &CommandHelp,
+    ) -> Vec<HelpMessage> {
+        let mut content = format!("{}\n", command.path);
+
+        if conf.show_aliases && !command.aliases().is_empty() {
+            writeln!(content, "Aliases: {}", command.aliases().join(", ")).unwrap();
+        }
+
+        if let Some(description) = &command.description {
+            writeln!(content, "{}", description).unwrap();
+        }
+
+        if let Some(usage) = &command.usage {
+            writeln!(content, "\nUsage: {}{} {}", prefix, command.path, usage).unwrap();
+        }
+
+        if !command.examples.is_empty() {
+            content.push_str("Examples:\n");
+
+            for example in &command.examples {
+                writeln!(content, "  {}{} {}", prefix, command.path, example).unwrap();
+            }
+        }
+
+        if choices(command).next().is_some() {
+            content.push_str("Choices:\n");
+
+            for (name, choices) in choices(command) {
+                writeln!(content, "  {}: {}", name, choices.join(", ")).unwrap();
+            }
+        }
+
+        if !command.subcommands.is_empty() {
+            let names = command.subcommands.iter().map(|c| c.name()).collect::<Vec<_>>();
+            writeln!(content, "Subcommands: {}", names.join(", ")).unwrap();
+        }
+
+        if !command.checks.is_empty() {
+            writeln!(content, "Requires: {}", command.checks.join(", ")).unwrap();
+        }
+
+        code_block_messages(&content)
+    }
+}
+
+/// A [`Renderer`] presenting the help content in an embed.
+#[derive(Debug, Default, Clone, Copy)]
+#[non_exhaustive]
+pub struct Embed {
+    /// The colour of the embed.
+    pub colour: Option<Colour>,
+}
+
+impl Embed {
+    /// Creates a new instance of the embed renderer.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Assigns the colour of the embed.
+    pub fn colour<C>(mut self, colour: C) -> Self
+    where
+        C: Into<Colour>,
+    {
+        self.colour = Some(colour.into());
+        self
+    }
+
+    /// Returns embeds with the title, description and footer, holding the fields.
+    ///
+    /// The fields are spread over as many embeds as necessary to stay within the
+    /// limits of Discord.
+    fn embeds(
+        &self,
+        title: &str,
+        description: Option<&str>,
+        fields: Vec<(String, String, bool)>,
+        footer: Option<&str>,
+    ) -> Vec<HelpMessage> {
+        let title = truncate(title, FIELD_NAME_LENGTH);
+        let description = description.map(|d| truncate(d, EMBED_DESCRIPTION_LENGTH));
+        let footer = footer.map(|f| truncate(f, MESSAGE_LENGTH));
+
+        let base_len = length(&title) + footer.as_deref().map_or(0, length);
+
+        let new_embed = |first: bool| {
+            let mut embed = CreateEmbed::default();
+
+            if let Some(colour) = self.colour {
+                embed.colour(colour);
+            }
+
+            embed.title(&title);
+
+            let mut len = base_len;
+
+            // The description is only displayed on the first embed.
+            if let Some(description) = description.as_deref().filter(|_| first) {
+                embed.description(description);
+                len += length(description);
+            }
+
+            if let Some(footer) = &footer {
+                embed.footer(|f| f.text(footer));
+            }
+
+            (embed, len, 0)
+        };
+
+        let mut messages = Vec::new();
+        let (mut embed, mut len, mut count) = new_embed(true);
+
+        for (name, value, inline) in fields {
+            let field_len = length(&name) + length(&value);
+
+            if count == EMBED_FIELDS || len + field_len > EMBED_LENGTH {
+                messages.push(HelpMessage::Embed(embed));
+                let next = new_embed(false);
+                embed = next.0;
+                len = next.1;
+                count = next.2;
+            }
+
+            embed.field(name, value, inline);
+            len += field_len;
+            count += 1;
+        }
+
+        messages.push(HelpMessage::Embed(embed));
+
+        messages
+    }
+}
+
+impl Renderer for Embed {
+    fn overview(&self, conf: &HelpConfiguration, tree: &HelpTree) -> Vec<HelpMessage> {
+        let mut fields = Vec::new();
+
+        for category in &tree.categories {
+            let mut value = String::new();
+
+            for command in &category.commands {
+                write!(value, "`{}`", command.name()).unwrap();
+
+                if let Some(summary) = summary(command) {
+                    write!(value, " - {}", summary).unwrap();
+                }
+
+                if !command.checks.is_empty() {
+                    write!(value, " *(Requires: {})*", command.checks.join(", ")).unwrap();
+                }
+
+                value.push('\n');
+            }
+
+            push_field(&mut fields, &category.name, &value, false);
+        }
+
+        let footer =
+            format!("Type {}help <command> for more information on a command.", tree.prefix);
+
+        self.embeds("Help", conf.header.as_deref(), fields, Some(&footer))
+    }
+
+    fn command(
+        &self,
+        conf: &HelpConfiguration,
+        prefix: &str,
+        command: &CommandHelp,
+    ) -> Vec<HelpMessage> {
+        let mut fields = Vec::new();
+
+        if conf.show_aliases && !command.aliases().is_empty() {
+            push_field(&mut fields, "Aliases", &code_list(command.aliases()), true);
+        }
+
+        if let Some(usage) = &command.usage {
+            let usage = format!("`{}{} {}`", prefix, command.path, usage);
+            push_field(&mut fields, "Usage", &usage, false);
+        }
+
+        if !command.examples.is_empty() {
+            let examples = command
+                .examples
+                .iter()
+                .map(|e| format!("`{}{} {}`", prefix, command.path, e))
+                .collect::<Vec<_>>();
+
+            push_field(&mut fields, "Examples", &examples.join("\n"), false);
+        }
+
+        if choices(command).next().is_some() {
+            let choices = choices(command)
+                .map(|(name, choices)| format!("`{}`: {}", name, code_list(choices)))
+                .collect::<Vec<_>>();
+
+            push_field(&mut fields, "Choices", &choices.join("\n"), false);
+        }
+
+        if !command.subcommands.is_empty() {
+            let names = command.subcommands.iter().map(|c| c.name()).collect::<Vec<_>>();
+            push_field(&mut fields, "Subcommands", &code_list(&names), false);
+        }
+
+        if !command.checks.is_empty() {
+            push_field(&mut fields, "Requires", &command.checks.join(", "), false);
+        }
+
+        self.embeds(&command.path, command.description.as_deref(), fields, None)
+    }
+}
+
+/// Returns the first line of the description of a command.
+fn summary(command: &CommandHelp) -> Option<&str> {
+    command.description.as_deref().and_then(|d| d.lines().next())
+}
+
+/// Returns the names of the parameters of a command chosen out of a fixed set,
+/// along with the names of their values.
+fn choices(command: &CommandHelp) -> impl Iterator<Item = (&str, &[&str])> {
+    command.parameters.iter().filter(|p| !p.choices.is_empty()).map(|p| (p.name, p.choices))
+}
+
+/// Returns a comma-separated list of inline code.
+fn code_list<S: AsRef<str>>(items: &[S]) -> String {
+    items.iter().map(|i| format!("`{}`", i.as_ref())).collect::<Vec<_>>().join(", ")
+}
+
+/// Splits text into chunks of at most `limit` characters.
+///
+/// Text is split at line breaks where possible. Lines longer than the limit
+/// are split at the limit. Whitespace at the end of chunks is removed, and
+/// empty chunks are omitted.
+///
+/// # Examples
+///
+/// ```rust
+/// use serenity_framework::help::render::split;
+///
+/// assert_eq!(split("ab\ncd\nef", 5), vec!["ab\ncd", "ef"]);
+/// assert_eq!(split("abcdefg\nh", 3), vec!["abc", "def", "g\nh"]);
+/// assert!(split("", 10).is_empty());
+/// ```
+pub fn split(text: &str, limit: usize) -> Vec<String> {
+    let mut chunks = Vec::new();
+    let mut chunk = String::new();
+    let mut len = 0;
+
+    let mut push = |chunk: &mut String| {
+        let trimmed = chunk.trim_end();
+
+        if !trimmed.is_empty() {
+            chunks.push(trimmed.to_string());
+        }
+
+        chunk.clear();
+    };
+
+    for mut line in text.split_inclusive('\n') {
+        loop {
+            let line_len = length(line.trim_end());
+
+            if len + line_len <= limit {
+                chunk.push_str(line);
+                len += length(line);
+                break;
+            }
+
+            if len > 0 {
+                push(&mut chunk);
+                len = 0;
+                continue;
+            }
+
+            let end = line.char_indices().nth(limit).map_or(line.len(), |(i, _)| i);
+            chunk.push_str(&line[..end]);
+            push(&mut chunk);
+            line = &line[end..];
+        }
+    }
+
+    push(&mut chunk);
+
+    chunks
+}
+
+/// Returns the length of text in characters.
+fn length(text: &str) -> usize {
+    text.chars().count()
+}
+
+/// Truncates text to at most `limit` characters, ending it with an ellipsis
+/// if it was truncated.
+fn truncate(text: &str, limit: usize) -> String {
+    if length(text) <= limit {
+        return text.to_string();
+    }
+
+    let mut text = text.chars().take(limit - 1).collect::<String>();
+    text.push('…');
+    text
+}
+
+/// Returns messages of text, split to fit the limit of a message.
+fn text_messages(content: &str) -> Vec<HelpMessage> {
+    split(content, MESSAGE_LENGTH).into_iter().map(HelpMessage::Text).collect()
+}
+
+/// Returns messages of text in code blocks, split to fit the limit of a message.
+fn code_block_messages(content: &str) -> Vec<HelpMessage> {
+    // Leave room for the fences of the code block.
+    split(content, MESSAGE_LENGTH - "```\n\n```".len())
+        .into_iter()
+        .map(|chunk| HelpMessage::Text(format!("```\n{}\n```", chunk)))
+        .collect()
+}
+
+/// Adds a field to a list of embed fields, split into several fields if the
+/// value is too long.
+fn push_field(fields: &mut Vec<(String, String, bool)>, name: &str, value: &str, inline: bool) {
+    for (i, chunk) in split(value, FIELD_VALUE_LENGTH).into_iter().enumerate() {
+        let name = if i == 0 { name.to_string() } else { format!("{} (continued)", name) };
+
+        fields.push((truncate(&name, FIELD_NAME_LENGTH), chunk, inline));
+    }
+}
@@ -30,9 +30,13 @@
 //! Commands may be *categorized*. A category is a list of individual commands
 //! with a common theme, such as moderation. They do not participate in command
 //! invocation. They are used to register commands in bulk and display related
-//! commands in the help command.
+//! commands in the [help command][help].
+//!
+//! How often commands can be invoked may be limited with [buckets].
 //!
 //! [Serenity]: https://github.com/serenity-rs/serenity
+//! [help]: crate::help
+//! [buckets]: crate::bucket
 
 #![warn(missing_docs)]
 
@@ -43,19 +47,21 @@ use serenity::model::channel::Message;
 use serenity::prelude::{Context as SerenityContext, Mutex};
 
 pub mod argument;
+pub mod bucket;
 pub mod category;
 pub mod check;
 pub mod command;
 pub mod configuration;
 pub mod context;
 pub mod error;
+pub mod help;
 pub mod parse;
 pub mod prelude;
 pub mod utils;
 
-use command::CommandFn;
+use command::{CommandFn, CommandId};
 use configuration::Configuration;
-use context::{CheckContext, Context};
+use context::{CheckContext, Context, ErrorContext};
 use error::{DispatchError, Error};
 use utils::Segments;
 
@@ -69,6 +75,11 @@ pub type DefaultData = ();
 /// [errors]: crate::command::CommandResult
 pub type DefaultError = Box<dyn StdError + Send + Sync>;
 
+// Used by the code generated for `#[regex(...)]` constraints.
+#[cfg(feature = "macros")]
+#[doc(hidden)]
+pub use regex;
+
 /// The core of the framework.
 #[derive(Clone)]
 pub struct Framework<D = DefaultData, E = DefaultError> {
@@ -117,16 +128,100 @@ impl<D, E> Framework<D, E> {
             data,
         }
     }
+}
 
+impl<D, E> Framework<D, E>
+where
+    D: Send + Sync,
+{
     /// Dispatches a command.
-    #[inline]
+    ///
+    /// The command is surrounded by the [`before`] and [`after`] hooks, if they are present.
+    /// If the [`before`] hook returns `false`, the command is not invoked and `Ok(())`
+    /// is returned. Otherwise, the invocation is counted towards the limits of the
+    /// [bucket] of the command.
+    ///
+    /// Errors are passed to the [`on_dispatch_error`] and [`on_command_error`] hooks,
+    /// if they are present, before they are returned.
+    ///
+    /// [`before`]: crate::configuration::Configuration::before
+    /// [`after`]: crate::configuration::Configuration::after
+    /// [`on_dispatch_error`]: crate::configuration::Configuration::on_dispatch_error
+    /// [`on_command_error`]: crate::configuration::Configuration::on_command_error
+    /// [bucket]: crate::bucket
     pub async fn dispatch(&self, ctx: &SerenityContext, msg: &Message) -> Result<(), Error<E>> {
-        let (ctx, func) = self.parse(ctx, msg).await?;
+        let (ctx, func) = match self.parse(ctx, msg).await {
+            Ok(pair) => pair,
+            Err(DispatchError::NormalMessage) => return Err(DispatchError::NormalMessage.into()),
+            Err(err) => {
+                let conf = self.conf.lock().await;
+
+                if let Some(hook) = conf.on_dispatch_error {
+                    let ctx = ErrorContext {
+                        data: &self.data,
+                        conf: &conf,
+                        serenity_ctx: ctx,
+                    };
+
+                    hook(&ctx, msg, &err).await;
+                }
+
+                return Err(err.into());
+            },
+        };
+
+        let (before, after, on_command_error) = {
+            let conf = self.conf.lock().await;
+            (conf.before, conf.after, conf.on_command_error)
+        };
+
+        if let Some(before) = before {
+            if !before(&ctx, msg).await {
+                return Ok(());
+            }
+        }
+
+        // Only invocations accepted by the `before` hook count towards the limits of buckets.
+        {
+            let conf = self.conf.lock().await;
+
+            if let Err(err) = take_bucket(&conf, ctx.command_id, msg) {
+                if let Some(hook) = conf.on_dispatch_error {
+                    let ctx = ErrorContext {
+                        data: &self.data,
+                        conf: &conf,
+                        serenity_ctx: &ctx.serenity_ctx,
+                    };
+
+                    hook(&ctx, msg, &err).await;
+                }
+
+                return Err(err.into());
+            }
+        }
 
-        func(ctx, msg).await.map_err(Error::User)
+        let result = func(ctx.clone(), msg).await;
+
+        if let Some(after) = after {
+            after(&ctx, msg, &result).await;
+        }
+
+        if let (Err(err), Some(hook)) = (&result, on_command_error) {
+            hook(&ctx, msg, err).await;
+        }
+
+        result.map_err(Error::User)
     }
 
     /// Parses a command out of a message, if one is present.
+    ///
+    /// The checks of the command are run, but invocations are not counted towards
+    /// the limits of its [bucket]. This is done by [`dispatch`], after the [`before`]
+    /// hook has accepted the invocation.
+    ///
+    /// [bucket]: crate::bucket
+    /// [`dispatch`]: Self::dispatch
+    /// [`before`]: crate::configuration::Configuration::before
     pub async fn parse(
         &self,
         ctx: &SerenityContext,
@@ -135,39 +230,37 @@ impl<D, E> Framework<D, E> {
         let (func, command_id, prefix, args) = {
             let conf = self.conf.lock().await;
 
-            let (prefix, content) = match parse::content(&self.data, &conf, &ctx, &msg).await {
+            let (prefix, content) = match parse::content(&self.data, &conf, ctx, msg).await {
                 Some(pair) => pair,
                 None => return Err(DispatchError::NormalMessage),
             };
 
-            let mut segments = Segments::new(&content, " ", conf.case_insensitive);
+            let mut segments = Segments::new(content, " ", conf.case_insensitive);
 
-            let mut command = None;
+            let mut chain = Vec::new();
 
             for cmd in parse::commands(&conf, &mut segments) {
-                let cmd = cmd?;
-
-                if let Some(check) = &cmd.check {
-                    let ctx = CheckContext {
-                        data: &self.data,
-                        conf: &conf,
-                        serenity_ctx: &ctx,
-                        command_id: cmd.id,
-                    };
-
-                    if let Err(reason) = (check.function)(&ctx, msg).await {
-                        return Err(DispatchError::CheckFailed(check.name.clone(), reason));
-                    }
-                }
-
-                command = Some(cmd);
+                chain.push(cmd?);
             }
 
-            let command = match command {
-                Some(cmd) => cmd,
+            let command = match chain.last() {
+                Some(cmd) => *cmd,
                 None => return Err(DispatchError::PrefixOnly(prefix.to_string())),
             };
 
+            let check_ctx = CheckContext {
+                data: &self.data,
+                conf: &conf,
+                serenity_ctx: ctx,
+                command_id: command.id,
+            };
+
+            for check in check::applicable(&conf, &chain) {
+                if let Err((name, reason)) = check.run(&check_ctx, msg).await {
+                    return Err(DispatchError::CheckFailed(name.to_string(), reason));
+                }
+            }
+
             let args = segments.source();
 
             (command.function, command.id, prefix.to_string(), args.to_string())
@@ -185,3 +278,23 @@ impl<D, E> Framework<D, E> {
         Ok((ctx, func))
     }
 }
+
+/// Registers an invocation of a command in its bucket, if it has one.
+fn take_bucket<D, E>(
+    conf: &Configuration<D, E>,
+    id: CommandId,
+    msg: &Message,
+) -> Result<(), DispatchError> {
+    let bucket = conf
+        .commands
+        .get(id)
+        .and_then(|command| command.bucket.as_ref())
+        .and_then(|name| conf.buckets.get(name));
+
+    match bucket {
+        Some(bucket) => bucket.take(msg).map_err(|retry_after| DispatchError::RateLimited {
+            retry_after,
+        }),
+        None => Ok(()),
+    }
+}
@@ -2,16 +2,17 @@
 //!
 //! Refer to the [`content`] function for the definition of a prefix.
 
+use std::collections::HashMap;
 use std::sync::Arc;
 
 use serenity::client::Context as SerenityContext;
 use serenity::model::channel::Message;
 
-use crate::command::Command;
+use crate::command::{Command, CommandId};
 use crate::configuration::Configuration;
 use crate::context::PrefixContext;
 use crate::error::DispatchError;
-use crate::utils::Segments;
+use crate::utils::{levenshtein, Segments};
 
 /// Parses a mention from the message.
 ///
@@ -105,7 +106,7 @@ pub async fn content<'a, D, E>(
     }
 
     if let Some(on_mention) = &conf.on_mention {
-        if let Some(pair) = mention(&msg.content, &on_mention) {
+        if let Some(pair) = mention(&msg.content, on_mention) {
             return Some(pair);
         }
     }
@@ -152,7 +153,11 @@ impl<'a, 'b, 'c, D, E> Iterator for CommandIterator<'a, 'b, 'c, D, E> {
                 // After the first command, we do not care if the "name" is invalid,
                 // as it may be the argument to the command at that point.
                 if self.command.is_none() {
-                    return Some(Err(DispatchError::InvalidCommandName(name.into_owned())));
+                    let suggestions = suggestions(self.conf, &name, None);
+                    return Some(Err(DispatchError::InvalidCommandName(
+                        name.into_owned(),
+                        suggestions,
+                    )));
                 }
 
                 return None;
@@ -210,3 +215,97 @@ pub fn commands<'a, 'b, 'c, D, E>(
         command: None,
     }
 }
+
+/// Returns names of commands similar to a name, ranked by their similarity.
+///
+/// If a parent command is specified, only its subcommands are considered. Otherwise,
+/// only [root level commands][root] are considered. Commands that are
+/// [not available in help][help] are never suggested.
+///
+/// A name is similar if it starts with the given name, or if its [edit distance][dist]
+/// to the given name is at most a third of the given name's length, but no less than one.
+/// Names are ranked by their edit distance. Only the most similar name of a command is
+/// returned, excluding its other aliases.
+///
+/// # Examples
+///
+/// ```rust
+/// use serenity::model::channel::Message;
+/// use serenity_framework::parse::suggestions;
+/// use serenity_framework::prelude::*;
+///
+/// #[command]
+/// async fn ping(_ctx: FrameworkContext, _msg: &Message) -> CommandResult {
+///     Ok(())
+/// }
+///
+/// #[command]
+/// async fn pong(_ctx: FrameworkContext, _msg: &Message) -> CommandResult {
+///     Ok(())
+/// }
+///
+/// #[command]
+/// async fn pinned(_ctx: FrameworkContext, _msg: &Message) -> CommandResult {
+///     Ok(())
+/// }
+///
+/// #[command]
+/// #[help_available(false)]
+/// async fn pine(_ctx: FrameworkContext, _msg: &Message) -> CommandResult {
+///     Ok(())
+/// }
+///
+/// let mut conf: Configuration = Configuration::new();
+/// conf.command(ping).command(pong).command(pinned).command(pine);
+///
+/// // `ping` is one edit away, and `pinned` starts with the name. `pong` is two edits
+/// // away, exceeding the threshold of one. `pine` is hidden from help.
+/// assert_eq!(suggestions(&conf, "pin", None), vec!["ping", "pinned"]);
+/// assert!(suggestions(&conf, "pnig", None).is_empty());
+/// ```
+///
+/// [root]: crate::configuration::Configuration::root_level_commands
+/// [help]: crate::command::Command::help_available
+/// [dist]: crate::utils::levenshtein
+pub fn suggestions<D, E>(
+    conf: &Configuration<D, E>,
+    name: &str,
+    parent: Option<&Command<D, E>>,
+) -> Vec<String> {
+    let threshold = (name.chars().count() / 3).max(1);
+
+    let mut best: HashMap<CommandId, (usize, &str)> = HashMap::new();
+
+    for candidate in conf.commands.iter_names() {
+        let id = match conf.commands.get_id(candidate.as_str()) {
+            Some(id) => id,
+            None => continue,
+        };
+
+        let eligible = match parent {
+            Some(parent) => parent.subcommands.contains(&id),
+            None => conf.root_level_commands.contains(&id),
+        };
+
+        if !eligible || !conf.commands.get(id).is_some_and(|cmd| cmd.help_available) {
+            continue;
+        }
+
+        let distance = levenshtein(name, candidate);
+
+        if distance > threshold && !candidate.starts_with(name) {
+            continue;
+        }
+
+        let entry = best.entry(id).or_insert((distance, candidate));
+
+        if (distance, candidate.as_str()) < *entry {
+            *entry = (distance, candidate);
+        }
+    }
+
+    let mut ranked = best.into_values().collect::<Vec<_>>();
+    ranked.sort_unstable();
+
+    ranked.into_iter().map(|(_, name)| name.to_string()).collect()
+}
@@ -9,10 +9,19 @@
 #[cfg(feature = "macros")]
 pub use command_attr::{check, command, hook};
 
+pub use crate::argument::{
+    Argument,
+    ArgumentError,
+    ArgumentErrorKind,
+    Arguments,
+    Choice,
+    Greedy,
+    ReferencedMessage,
+};
 pub use crate::category::Category;
 pub use crate::check::{Check, CheckResult, Reason};
 pub use crate::command::{Command, CommandResult};
 pub use crate::configuration::Configuration;
-pub use crate::context::{CheckContext, Context as FrameworkContext};
+pub use crate::context::{CheckContext, Context as FrameworkContext, ErrorContext};
 pub use crate::error::{DispatchError, Error as FrameworkError};
 pub use crate::Framework;
@@ -0,0 +1,37 @@
+//! Functions for measuring the similarity of strings.
+
+/// Returns the Levenshtein distance between two strings.
+///
+/// The distance is the minimal number of single-character insertions, deletions,
+/// or substitutions required to change one string into the other.
+///
+/// # Examples
+///
+/// ```rust
+/// use serenity_framework::utils::levenshtein;
+///
+/// assert_eq!(levenshtein("ban", "ban"), 0);
+/// assert_eq!(levenshtein("ban", "bna"), 2);
+/// assert_eq!(levenshtein("kick", "kcik"), 2);
+/// assert_eq!(levenshtein("ping", "pong"), 1);
+/// assert_eq!(levenshtein("", "help"), 4);
+/// ```
+pub fn levenshtein(a: &str, b: &str) -> usize {
+    let b = b.chars().collect::<Vec<_>>();
+
+    // Only the previous row of the distance matrix is necessary to compute the next.
+    let mut row = (0..=b.len()).collect::<Vec<_>>();
+
+    for (i, ca) in a.chars().enumerate() {
+        let mut diagonal = row[0];
+        row[0] = i + 1;
+
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = diagonal + usize::from(ca != *cb);
+            diagonal = row[j + 1];
+            row[j + 1] = substitution.min(row[j] + 1).min(diagonal + 1);
+        }
+    }
+
+    row[b.len()]
+}
@@ -187,10 +187,10 @@ where
     ///
     /// Returns `None` if an identifier does not belong to the name,
     /// otherwise `Some`.
-    pub fn get_id<B: ?Sized>(&self, name: &B) -> Option<Id>
+    pub fn get_id<B>(&self, name: &B) -> Option<Id>
     where
         Name: Borrow<B>,
-        B: Hash + Eq,
+        B: ?Sized + Hash + Eq,
     {
         self.name_to_id.get(name).copied()
     }
@@ -201,10 +201,10 @@ where
     ///
     /// Returns `None` if a structure does not belong to the name,
     /// otherwise `Some`.
-    pub fn get_by_name<B: ?Sized>(&self, name: &B) -> Option<&Struct>
+    pub fn get_by_name<B>(&self, name: &B) -> Option<&Struct>
     where
         Name: Borrow<B>,
-        B: Hash + Eq,
+        B: ?Sized + Hash + Eq,
     {
         self.get_id(name).and_then(|id| self.structures.get(&id))
     }
@@ -215,10 +215,10 @@ where
     ///
     /// Returns `None` if a structure does not belong to the name,
     /// otherwise `Some`.
-    pub fn get_by_name_mut<B: ?Sized>(&mut self, name: &B) -> Option<&mut Struct>
+    pub fn get_by_name_mut<B>(&mut self, name: &B) -> Option<&mut Struct>
     where
         Name: Borrow<B>,
-        B: Hash + Eq,
+        B: ?Sized + Hash + Eq,
     {
         self.get_id(name).and_then(move |id| self.structures.get_mut(&id))
     }
@@ -229,20 +229,20 @@ where
     ///
     /// Returns `None` if a identifier/structure does not belong to the name,
     /// otherwise `Some`.
-    pub fn get_pair<B: ?Sized>(&self, name: &B) -> Option<(Id, &Struct)>
+    pub fn get_pair<B>(&self, name: &B) -> Option<(Id, &Struct)>
     where
         Name: Borrow<B>,
-        B: Hash + Eq,
+        B: ?Sized + Hash + Eq,
     {
         let id = self.get_id(name)?;
         self.structures.get(&id).map(|aggr| (id, aggr))
     }
 
     /// Returns a boolean indicating that a structure exists under a name.
-    pub fn contains<B: ?Sized>(&self, name: &B) -> bool
+    pub fn contains<B>(&self, name: &B) -> bool
     where
         Name: Borrow<B>,
-        B: Hash + Eq,
+        B: ?Sized + Hash + Eq,
     {
         match self.get_id(name) {
             Some(id) => self.contains_id(id),
@@ -2,8 +2,10 @@
 //!
 //! Usable outside of the framework.
 
+pub mod distance;
 pub mod id_map;
 pub mod segments;
 
+pub use distance::*;
 pub use id_map::*;
 pub use segments::*;
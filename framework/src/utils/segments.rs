@@ -4,6 +4,8 @@
 //! are determined by a delimiter, which is a &[`str`] value.
 
 use std::borrow::Cow;
+use std::error::Error as StdError;
+use std::fmt;
 
 /// Returns the index to the end of a segment in the source.
 ///
@@ -19,7 +21,7 @@ use std::borrow::Cow;
 /// assert_eq!(segment_index("world", " "), "world".len());
 /// ```
 pub fn segment_index(src: &str, delimiter: &str) -> usize {
-    src.find(delimiter).unwrap_or_else(|| src.len())
+    src.find(delimiter).unwrap_or(src.len())
 }
 
 /// Returns a segment of the source.
@@ -160,128 +162,303 @@ impl<'a> Iterator for Segments<'a> {
     }
 }
 
+/// The default set of quotation marks, as pairs of opening and closing marks.
+///
+/// These are straight double quotes, as well as typographic double quotes,
+/// which are sent by some mobile keyboards. Single quotes are not included,
+/// as they are used as apostrophes:
+///
+/// ```rust
+/// use serenity_framework::utils::ArgumentSegments;
+///
+/// let mut iter = ArgumentSegments::new("'twas “the night”", " ");
+///
+/// assert!(iter.check_quotes().is_ok());
+/// assert_eq!(iter.next().as_deref(), Some("'twas"));
+/// assert_eq!(iter.next().as_deref(), Some("the night"));
+/// ```
+pub const DEFAULT_QUOTES: &[(char, char)] = &[('"', '"'), ('\u{201C}', '\u{201D}')];
+
+/// Error that occurs when a quoted segment is missing its closing quotation mark.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UnbalancedQuote {
+    /// The opening quotation mark.
+    pub open: char,
+    /// The missing closing quotation mark.
+    pub close: char,
+}
+
+impl fmt::Display for UnbalancedQuote {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "missing closing quotation mark `{}`", self.close)
+    }
+}
+
+impl StdError for UnbalancedQuote {}
+
+/// Returns a boolean indicating that a backslash escapes the first character
+/// of the text after it.
+///
+/// Only backslashes, quotation marks and the start of the delimiter are escaped.
+fn is_escape(rest: &str, delimiter: &str, quotes: &[(char, char)]) -> bool {
+    let next = match rest.chars().next() {
+        Some(c) => c,
+        None => return false,
+    };
+
+    next == '\\'
+        || (!delimiter.is_empty() && rest.starts_with(delimiter))
+        || quotes.iter().any(|&(open, close)| next == open || next == close)
+}
+
+/// Removes backslashes escaping characters.
+///
+/// A backslash escapes a backslash, a quotation mark out of the set, or the
+/// start of the `delimiter` after it, which is then kept verbatim. Other
+/// backslashes are kept, preserving paths, regular expressions and the escapes
+/// of Discord markdown.
+///
+/// # Examples
+///
+/// ```rust
+/// use serenity_framework::utils::{unescape, DEFAULT_QUOTES};
+///
+/// assert_eq!(unescape("plain", " ", DEFAULT_QUOTES), "plain");
+/// assert_eq!(unescape(r#"say \"hi\""#, " ", DEFAULT_QUOTES), r#"say "hi""#);
+/// assert_eq!(unescape(r"back\\slash\", " ", DEFAULT_QUOTES), r"back\slash\");
+/// assert_eq!(unescape(r"one\ word", " ", DEFAULT_QUOTES), "one word");
+/// assert_eq!(unescape(r"C:\path\to \d+ \*", " ", DEFAULT_QUOTES), r"C:\path\to \d+ \*");
+/// ```
+pub fn unescape<'a>(src: &'a str, delimiter: &str, quotes: &[(char, char)]) -> Cow<'a, str> {
+    if !src.contains('\\') {
+        return Cow::Borrowed(src);
+    }
+
+    let mut result = String::with_capacity(src.len());
+    let mut chars = src.char_indices();
+
+    while let Some((index, c)) = chars.next() {
+        if c == '\\' && is_escape(&src[(index + 1)..], delimiter, quotes) {
+            result.extend(chars.next().map(|(_, c)| c));
+        } else {
+            result.push(c);
+        }
+    }
+
+    Cow::Owned(result)
+}
+
+/// Returns the index of the first occurence of a delimiter in the source
+/// that is not escaped with a backslash.
+///
+/// If the delimiter could not be found in the source, the length of the source
+/// is returned instead.
+fn unescaped_index(src: &str, delimiter: &str, quotes: &[(char, char)]) -> usize {
+    let mut chars = src.char_indices();
+
+    while let Some((index, c)) = chars.next() {
+        if c == '\\' && is_escape(&src[(index + 1)..], delimiter, quotes) {
+            chars.next();
+        } else if src[index..].starts_with(delimiter) {
+            return index;
+        }
+    }
+
+    src.len()
+}
+
 /// Returns a quoted segment and the rest of the source.
 ///
-/// A quoted segment is a part of the source that is encompassed by quotation marks.
-/// Or, if a leading quotation mark exists, but the trailing mark is missing,
-/// the quoted segment is the rest of the source excluding the leading mark.
+/// A quoted segment is a part of the source that is encompassed by a pair of
+/// quotation marks from the given set. Quotation marks and backslashes inside the
+/// segment may be escaped with a backslash. Escaping backslashes are removed from
+/// the segment; other backslashes are kept.
 ///
-/// If the source is empty or the source does not start with a leading quotation mark,
-/// `None` is returned.
+/// If the source is empty or the source does not start with an opening quotation mark,
+/// `Ok(None)` is returned. If the closing quotation mark is missing, an error is returned.
 ///
 /// # Examples
 ///
 /// ```
 /// // Used example strings are from the YouTube video https://www.youtube.com/watch?v=1edPxKqiptw
-/// use serenity_framework::utils::quoted_segment_split;
+/// use serenity_framework::utils::{quoted_segment_split, UnbalancedQuote, DEFAULT_QUOTES};
 ///
-/// assert_eq!(quoted_segment_split(""), None);
-/// assert_eq!(quoted_segment_split("Doll and roll"), None);
-/// assert_eq!(quoted_segment_split("\"and some\" and home."), Some(("and some", " and home.")));
+/// assert_eq!(quoted_segment_split("", DEFAULT_QUOTES), Ok(None));
+/// assert_eq!(quoted_segment_split("Doll and roll", DEFAULT_QUOTES), Ok(None));
+/// assert_eq!(
+///     quoted_segment_split("\"and some\" and home.", DEFAULT_QUOTES),
+///     Ok(Some(("and some".into(), " and home.")))
+/// );
+/// assert_eq!(
+///     quoted_segment_split("“Stranger” does not", DEFAULT_QUOTES),
+///     Ok(Some(("Stranger".into(), " does not")))
+/// );
 /// assert_eq!(
-///     quoted_segment_split("\"Stranger does not rhyme with anger"),
-///     Some(("Stranger does not rhyme with anger", ""))
+///     quoted_segment_split(r#""rhyme \"with\" anger""#, DEFAULT_QUOTES),
+///     Ok(Some((r#"rhyme "with" anger"#.into(), "")))
+/// );
+/// assert_eq!(
+///     quoted_segment_split("\"Stranger does not rhyme with anger", DEFAULT_QUOTES),
+///     Err(UnbalancedQuote {
+///         open: '"',
+///         close: '"'
+///     })
 /// );
 /// ```
-pub fn quoted_segment_split(src: &str) -> Option<(&str, &str)> {
-    if src.is_empty() || !src.starts_with('"') {
-        return None;
+pub fn quoted_segment_split<'a>(
+    src: &'a str,
+    quotes: &[(char, char)],
+) -> Result<Option<(Cow<'a, str>, &'a str)>, UnbalancedQuote> {
+    let first = match src.chars().next() {
+        Some(c) => c,
+        None => return Ok(None),
+    };
+
+    let (open, close) = match quotes.iter().find(|(open, _)| *open == first) {
+        Some(pair) => *pair,
+        None => return Ok(None),
+    };
+
+    let src = &src[open.len_utf8()..];
+    let mut chars = src.char_indices();
+
+    while let Some((index, c)) = chars.next() {
+        // The delimiter does not need to be escaped inside quotation marks.
+        if c == '\\' && is_escape(&src[(index + 1)..], "", quotes) {
+            chars.next();
+        } else if c == close {
+            let segment = unescape(&src[..index], "", quotes);
+            return Ok(Some((segment, &src[(index + close.len_utf8())..])));
+        }
     }
 
-    let src = &src[1..];
-
-    match src.find('"') {
-        Some(index) => Some((&src[..index], &src[(index + 1)..])),
-        None => Some((src, "")),
-    }
+    Err(UnbalancedQuote {
+        open,
+        close,
+    })
 }
 
 /// Returns a quoted segment of the source.
 ///
 /// Refer to [`quoted_segment_split`] for the definition of a quoted segment.
 ///
-/// If the source is empty or the source does not start with a leading quotation mark,
-/// `None` is returned.
+/// If the source is empty or the source does not start with an opening quotation mark,
+/// `Ok(None)` is returned. If the closing quotation mark is missing, an error is returned.
 ///
 /// # Examples
 ///
 /// ```
 /// // Used example strings are from the YouTube video https://www.youtube.com/watch?v=1edPxKqiptw
-/// use serenity_framework::utils::quoted_segment;
+/// use serenity_framework::utils::{quoted_segment, DEFAULT_QUOTES};
 ///
-/// assert_eq!(quoted_segment(""), None);
-/// assert_eq!(quoted_segment("Neither does devour with clangour"), None);
-/// assert_eq!(quoted_segment("\"Souls but\" foul"), Some("Souls but"));
-/// assert_eq!(quoted_segment("\"haunt but aunt"), Some("haunt but aunt"));
+/// assert_eq!(quoted_segment("", DEFAULT_QUOTES), Ok(None));
+/// assert_eq!(quoted_segment("Neither does devour with clangour", DEFAULT_QUOTES), Ok(None));
+/// assert_eq!(quoted_segment("\"Souls but\" foul", DEFAULT_QUOTES), Ok(Some("Souls but".into())));
+/// assert!(quoted_segment("\"haunt but aunt", DEFAULT_QUOTES).is_err());
 /// ```
-pub fn quoted_segment(src: &str) -> Option<&str> {
-    quoted_segment_split(src).map(|(seg, _)| seg)
+pub fn quoted_segment<'a>(
+    src: &'a str,
+    quotes: &[(char, char)],
+) -> Result<Option<Cow<'a, str>>, UnbalancedQuote> {
+    quoted_segment_split(src, quotes).map(|pair| pair.map(|(seg, _)| seg))
 }
 
 /// Returns an argument segment and the rest of the source.
 ///
 /// An argument segment is either [a quoted segment][qseg]
-/// or [a normal segment][seg].
+/// or a normal segment. A normal segment ends at the first occurence of the
+/// `delimiter` that is not escaped with a backslash. Escaping backslashes
+/// are removed from the segment; refer to [`unescape`].
 ///
-/// When the segment is quoted, the rest of the source is trimmed off of
-/// the specified `delimiter`.
+/// The rest of the source is trimmed off of the specified `delimiter`.
 ///
-/// If the source is empty, `None` is returned.
+/// If the source is empty, `Ok(None)` is returned. If the closing quotation mark
+/// of a quoted segment is missing, an error is returned.
 ///
 /// # Examples
 ///
 /// ```
 /// // Used example strings are from the YouTube video https://www.youtube.com/watch?v=1edPxKqiptw
-/// use serenity_framework::utils::argument_segment_split;
+/// use serenity_framework::utils::{argument_segment_split, DEFAULT_QUOTES};
 ///
-/// assert_eq!(argument_segment_split("", ", "), None);
-/// assert_eq!(argument_segment_split("Font, front, wont", ", "), Some(("Font", "front, wont")));
+/// assert_eq!(argument_segment_split("", ", ", DEFAULT_QUOTES), Ok(None));
+/// assert_eq!(
+///     argument_segment_split("Font, front, wont", ", ", DEFAULT_QUOTES),
+///     Ok(Some(("Font".into(), "front, wont")))
+/// );
+/// assert_eq!(
+///     argument_segment_split("\"want, grand\", and grant", ", ", DEFAULT_QUOTES),
+///     Ok(Some(("want, grand".into(), "and grant")))
+/// );
 /// assert_eq!(
-///     argument_segment_split("\"want, grand\", and grant", ", "),
-///     Some(("want, grand", "and grant"))
+///     argument_segment_split(r"Shoes\, goes, does.", ", ", DEFAULT_QUOTES),
+///     Ok(Some(("Shoes, goes".into(), "does.")))
 /// );
 /// assert_eq!(
-///     argument_segment_split("\"Shoes, goes, does.", ", "),
-///     Some(("Shoes, goes, does.", ""))
+///     argument_segment_split(r"Toes\hoes, ", ", ", DEFAULT_QUOTES),
+///     Ok(Some((r"Toes\hoes".into(), "")))
 /// );
+/// assert!(argument_segment_split("\"Shoes, goes, does.", ", ", DEFAULT_QUOTES).is_err());
 /// ```
 ///
 /// [qseg]: quoted_segment_split
-/// [seg]: segment
-pub fn argument_segment_split<'a>(src: &'a str, delimiter: &str) -> Option<(&'a str, &'a str)> {
-    match quoted_segment_split(src) {
-        Some((segment, rest)) => Some((segment, rest.trim_start_matches(delimiter))),
-        None => segment_split(src, delimiter),
+pub fn argument_segment_split<'a>(
+    src: &'a str,
+    delimiter: &str,
+    quotes: &[(char, char)],
+) -> Result<Option<(Cow<'a, str>, &'a str)>, UnbalancedQuote> {
+    if src.is_empty() {
+        return Ok(None);
     }
+
+    if let Some((segment, rest)) = quoted_segment_split(src, quotes)? {
+        return Ok(Some((segment, rest.trim_start_matches(delimiter))));
+    }
+
+    let (segment, rest) = src.split_at(unescaped_index(src, delimiter, quotes));
+
+    Ok(Some((unescape(segment, delimiter, quotes), rest.trim_start_matches(delimiter))))
 }
 
 /// Returns an argument segment of the source.
 ///
 /// Refer to [`argument_segment_split`] for the definition of an argument segment.
 ///
-/// If the source is empty, `None` is returned.
+/// If the source is empty, `Ok(None)` is returned. If the closing quotation mark
+/// of a quoted segment is missing, an error is returned.
 ///
 /// # Examples
 ///
 /// ```
 /// // Used example strings are from the YouTube video https://www.youtube.com/watch?v=1edPxKqiptw
-/// use serenity_framework::utils::argument_segment;
+/// use serenity_framework::utils::{argument_segment, DEFAULT_QUOTES};
 ///
-/// assert_eq!(argument_segment("", ", "), None);
-/// assert_eq!(argument_segment("Now first say finger, ", ", "), Some("Now first say finger"));
+/// assert_eq!(argument_segment("", ", ", DEFAULT_QUOTES), Ok(None));
+/// assert_eq!(
+///     argument_segment("Now first say finger, ", ", ", DEFAULT_QUOTES),
+///     Ok(Some("Now first say finger".into()))
+/// );
 /// assert_eq!(
-///     argument_segment("\"And then singer, ginger\", linger, ", ", "),
-///     Some("And then singer, ginger")
+///     argument_segment("\"And then singer, ginger\", linger, ", ", ", DEFAULT_QUOTES),
+///     Ok(Some("And then singer, ginger".into()))
 /// );
-/// assert_eq!(argument_segment("\"Real, zeal, mauve", ", "), Some("Real, zeal, mauve"));
+/// assert!(argument_segment("\"Real, zeal, mauve", ", ", DEFAULT_QUOTES).is_err());
 /// ```
-
-pub fn argument_segment<'a>(src: &'a str, delimiter: &str) -> Option<&'a str> {
-    argument_segment_split(src, delimiter).map(|(seg, _)| seg)
+pub fn argument_segment<'a>(
+    src: &'a str,
+    delimiter: &str,
+    quotes: &[(char, char)],
+) -> Result<Option<Cow<'a, str>>, UnbalancedQuote> {
+    argument_segment_split(src, delimiter, quotes).map(|pair| pair.map(|(seg, _)| seg))
 }
 
 /// An iterator type that splits a string into [argument segments][aseg] using a delimiter and quotes.
 ///
+/// It returns [`Cow`] values, as escaping backslashes are removed from segments.
+///
+/// A segment with a missing closing quotation mark is the rest of the source,
+/// excluding the opening mark. Use [`check_quotes`] to detect this beforehand.
+///
 /// # Examples
 ///
 /// ```rust
@@ -290,25 +467,63 @@ pub fn argument_segment<'a>(src: &'a str, delimiter: &str) -> Option<&'a str> {
 ///
 /// let mut iter = ArgumentSegments::new("Marriage, \"foliage, mirage\", \"and age.", ", ");
 ///
-/// assert_eq!(iter.next(), Some("Marriage"));
-/// assert_eq!(iter.next(), Some("foliage, mirage"));
-/// assert_eq!(iter.next(), Some("and age."));
+/// assert_eq!(iter.next().as_deref(), Some("Marriage"));
+/// assert_eq!(iter.next().as_deref(), Some("foliage, mirage"));
+/// assert!(iter.check_quotes().is_err());
+/// assert_eq!(iter.next().as_deref(), Some("and age."));
+/// assert_eq!(iter.next(), None);
+/// ```
+///
+/// The set of quotation marks is configurable:
+///
+/// ```rust
+/// use serenity_framework::utils::ArgumentSegments;
+///
+/// let mut iter = ArgumentSegments::with_quotes("'Heard' «a word»", " ", &[('«', '»')]);
+///
+/// assert_eq!(iter.next().as_deref(), Some("'Heard'"));
+/// assert_eq!(iter.next().as_deref(), Some("a word"));
+/// assert_eq!(iter.next(), None);
+/// ```
+///
+/// If the delimiter is empty, the whole source is a single segment, without
+/// considering quotes and escapes:
+///
+/// ```rust
+/// use serenity_framework::utils::ArgumentSegments;
+///
+/// let mut iter = ArgumentSegments::new("\"Carriage\\\" and average", "");
+///
+/// assert_eq!(iter.next().as_deref(), Some("\"Carriage\\\" and average"));
 /// assert_eq!(iter.next(), None);
 /// ```
 ///
 /// [aseg]: argument_segment_split
+/// [`check_quotes`]: Self::check_quotes
 #[derive(Debug, Clone)]
 pub struct ArgumentSegments<'a> {
     src: &'a str,
+    len: usize,
     delimiter: &'a str,
+    quotes: &'a [(char, char)],
 }
 
 impl<'a> ArgumentSegments<'a> {
     /// Creates a new `ArgumentSegments` instance.
+    ///
+    /// Segments are quoted with the [default quotation marks][DEFAULT_QUOTES].
     pub fn new(src: &'a str, delimiter: &'a str) -> Self {
+        Self::with_quotes(src, delimiter, DEFAULT_QUOTES)
+    }
+
+    /// Creates a new `ArgumentSegments` instance with a set of quotation marks,
+    /// as pairs of opening and closing marks.
+    pub fn with_quotes(src: &'a str, delimiter: &'a str, quotes: &'a [(char, char)]) -> Self {
         Self {
             src,
+            len: src.len(),
             delimiter,
+            quotes,
         }
     }
 
@@ -318,30 +533,125 @@ impl<'a> ArgumentSegments<'a> {
     }
 
     /// Sets the new source string from which segments are constructed.
+    ///
+    /// To keep the [offset] meaningful, the new source should be a suffix
+    /// of the original source.
+    ///
+    /// [offset]: Self::offset
     pub fn set_source(&mut self, src: &'a str) {
         self.src = src;
     }
 
+    /// Returns the byte index of the source string in the original source.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity_framework::utils::ArgumentSegments;
+    ///
+    /// let mut iter = ArgumentSegments::new("hello world", " ");
+    ///
+    /// assert_eq!(iter.offset(), 0);
+    /// iter.next();
+    /// assert_eq!(iter.offset(), 6);
+    /// ```
+    pub fn offset(&self) -> usize {
+        self.len - self.src.len()
+    }
+
     /// Returns the delimiter string that is used to determine the boundaries
     /// of a segment.
     pub fn delimiter(&self) -> &'a str {
         self.delimiter
     }
 
+    /// Returns the set of quotation marks, as pairs of opening and closing marks.
+    pub fn quotes(&self) -> &'a [(char, char)] {
+        self.quotes
+    }
+
     /// Returns a boolean indicating that the source string is empty.
     pub fn is_empty(&self) -> bool {
         self.src.is_empty()
     }
+
+    /// Returns the current position in the source string, to [rewind] to later.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity_framework::utils::ArgumentSegments;
+    ///
+    /// let mut iter = ArgumentSegments::new("hello world", " ");
+    ///
+    /// let checkpoint = iter.checkpoint();
+    /// assert_eq!(iter.next().as_deref(), Some("hello"));
+    /// iter.rewind(checkpoint);
+    /// assert_eq!(iter.next().as_deref(), Some("hello"));
+    /// assert_eq!(iter.next().as_deref(), Some("world"));
+    /// ```
+    ///
+    /// [rewind]: Self::rewind
+    pub fn checkpoint(&self) -> Checkpoint<'a> {
+        Checkpoint {
+            src: self.src,
+        }
+    }
+
+    /// Returns to a position in the source string saved by [`checkpoint`].
+    ///
+    /// Segments constructed since the checkpoint are constructed again.
+    ///
+    /// [`checkpoint`]: Self::checkpoint
+    pub fn rewind(&mut self, checkpoint: Checkpoint<'a>) {
+        self.src = checkpoint.src;
+    }
+
+    /// Checks whether the next segment is missing its closing quotation mark.
+    pub fn check_quotes(&self) -> Result<(), UnbalancedQuote> {
+        if self.delimiter.is_empty() {
+            return Ok(());
+        }
+
+        quoted_segment_split(self.src, self.quotes).map(|_| ())
+    }
+}
+
+/// A position in the source string of [`ArgumentSegments`].
+///
+/// Refer to [`ArgumentSegments::checkpoint`].
+#[derive(Debug, Clone, Copy)]
+pub struct Checkpoint<'a> {
+    src: &'a str,
 }
 
 impl<'a> Iterator for ArgumentSegments<'a> {
-    type Item = &'a str;
+    type Item = Cow<'a, str>;
 
     fn next(&mut self) -> Option<Self::Item> {
-        let (segment, rest) = argument_segment_split(self.src, self.delimiter)?;
+        if self.delimiter.is_empty() {
+            return Some(std::mem::take(&mut self.src))
+                .filter(|src| !src.is_empty())
+                .map(Cow::Borrowed);
+        }
 
-        self.src = rest;
+        match argument_segment_split(self.src, self.delimiter, self.quotes) {
+            Ok(pair) => {
+                let (segment, rest) = pair?;
+
+                self.src = rest;
 
-        Some(segment)
+                Some(segment)
+            },
+            Err(UnbalancedQuote {
+                open, ..
+            }) => {
+                let segment = unescape(&self.src[open.len_utf8()..], "", self.quotes);
+
+                self.src = "";
+
+                Some(segment)
+            },
+        }
     }
 }